@@ -0,0 +1,139 @@
+//! Benchmarks of the parse and solve phases of every day on its puzzle input.
+//!
+//! Run with `cargo bench --bench days`, or `cargo bench --bench days -- day03`
+//! for a single day. Days without an input in `inputs/` are skipped. Day 3 is
+//! also benchmarked on generated banks much longer than the puzzle's. Besides
+//! the Criterion reports, a summary of the estimates is written to
+//! `target/bench-report.json` (or the path in `AOC_BENCH_REPORT`), with the
+//! benchmarks sorted by ID so it can be diffed across commits.
+
+use std::{
+    collections::BTreeMap,
+    hint::black_box,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use aoc2025::{
+    days::day03,
+    generate::{self, Rng},
+    input::Source,
+};
+use criterion::Criterion;
+use serde::Serialize;
+
+fn bench_days(criterion: &mut Criterion) -> Vec<String> {
+    let mut ids = Vec::new();
+    for day in aoc2025::days() {
+        let input = match Source::from_arg(day.number(), None).read() {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("Skipping day {}: {}", day.number(), error);
+                continue;
+            }
+        };
+        let data = match day.parse_boxed(&input) {
+            Ok(data) => data,
+            Err(error) => {
+                eprintln!("Skipping day {}:\n{}", day.number(), error.render());
+                continue;
+            }
+        };
+
+        let name = format!("day{:02}", day.number());
+        let mut group = criterion.benchmark_group(&name);
+        group.bench_function("parse", |b| b.iter(|| day.parse_boxed(black_box(&input))));
+        group.bench_function("part1", |b| {
+            b.iter(|| day.part1_boxed(black_box(data.as_ref())))
+        });
+        group.bench_function("part2", |b| {
+            b.iter(|| day.part2_boxed(black_box(data.as_ref())))
+        });
+        group.finish();
+
+        ids.extend(["parse", "part1", "part2"].map(|phase| format!("{}/{}", name, phase)));
+    }
+    ids
+}
+
+/// Picks batteries from banks of 100k and 1M batteries, to check that the time
+/// grows linearly with the length of the bank and not with the picks.
+fn bench_long_banks(criterion: &mut Criterion) -> Vec<String> {
+    let mut ids = Vec::new();
+    let mut group = criterion.benchmark_group("day03_long_banks");
+    for length in [100_000, 1_000_000] {
+        let bank = generate::day03_bank(&mut Rng::new(0), length);
+        for count in [12, length / 2] {
+            let id = format!("{}_of_{}", count, length);
+            group.bench_function(&id, |b| {
+                b.iter(|| day03::largest_digits(black_box(&bank), count))
+            });
+            ids.push(format!("day03_long_banks/{}", id));
+        }
+    }
+    group.finish();
+    ids
+}
+
+/// Reads the estimates of a benchmark saved by Criterion, if they were
+/// updated by this run. Returns the mean, median and standard deviation in
+/// nanoseconds.
+fn read_estimates(directory: &Path, id: &str, since: SystemTime) -> Option<(f64, f64, f64)> {
+    let path = directory.join(id).join("new").join("estimates.json");
+    if std::fs::metadata(&path).ok()?.modified().ok()? < since {
+        return None;
+    }
+
+    let estimates: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(path).ok()?).ok()?;
+    let estimate = |name: &str| estimates[name]["point_estimate"].as_f64();
+    Some((estimate("mean")?, estimate("median")?, estimate("std_dev")?))
+}
+
+/// Estimates of a benchmark in the report, in nanoseconds.
+#[derive(Serialize)]
+struct Estimates {
+    mean_ns: f64,
+    median_ns: f64,
+    std_dev_ns: f64,
+}
+
+fn write_report(ids: &[String], since: SystemTime) -> std::io::Result<PathBuf> {
+    let target =
+        std::env::var_os("CARGO_TARGET_DIR").map_or(PathBuf::from("target"), PathBuf::from);
+    let criterion = target.join("criterion");
+
+    // Rounded to a tenth of nanosecond, like the estimates printed by Criterion
+    let round = |estimate: f64| (estimate * 10.0).round() / 10.0;
+    let report: BTreeMap<&str, Estimates> = ids
+        .iter()
+        .filter_map(|id| {
+            let (mean, median, std_dev) = read_estimates(&criterion, id, since)?;
+            let estimates = Estimates {
+                mean_ns: round(mean),
+                median_ns: round(median),
+                std_dev_ns: round(std_dev),
+            };
+            Some((id.as_str(), estimates))
+        })
+        .collect();
+
+    let path = std::env::var_os("AOC_BENCH_REPORT")
+        .map_or(target.join("bench-report.json"), PathBuf::from);
+    let json = serde_json::to_string_pretty(&report).expect("The report is always serializable");
+    std::fs::write(&path, json + "\n")?;
+    Ok(path)
+}
+
+fn main() {
+    let start = SystemTime::now();
+    let mut criterion = Criterion::default().configure_from_args();
+    let mut ids = bench_days(&mut criterion);
+    ids.extend(bench_long_banks(&mut criterion));
+    criterion.final_summary();
+
+    match write_report(&ids, start) {
+        Ok(path) => println!("Report written to {}", path.display()),
+        Err(error) => eprintln!("Unable to write the report: {}", error),
+    }
+}
@@ -0,0 +1,249 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::runner::Part;
+
+/// An answer as written in an answers file. Small answers can be written as
+/// TOML integers, the others must be strings.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum RawAnswer {
+    Integer(i64),
+    Text(String),
+}
+
+impl From<RawAnswer> for String {
+    fn from(answer: RawAnswer) -> Self {
+        match answer {
+            RawAnswer::Integer(answer) => answer.to_string(),
+            RawAnswer::Text(answer) => answer,
+        }
+    }
+}
+
+/// Confirmed answers for one input of a day.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct Answers {
+    #[serde(default, deserialize_with = "deserialize_answer")]
+    pub part1: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_answer")]
+    pub part2: Option<String>,
+    /// Hash of the input the answers were computed on, as 16 hexadecimal
+    /// digits, see `runner::hash_input`.
+    #[serde(default)]
+    pub hash: Option<String>,
+}
+
+fn deserialize_answer<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(Some(RawAnswer::deserialize(deserializer)?.into()))
+}
+
+impl Answers {
+    /// Checks if the answers hold for the input with the given hash. Answers
+    /// with a hash only hold for that input. Answers without one hold for the
+    /// input they're named after, when `named_input` tells it's the one read.
+    pub fn holds_for(&self, input_hash: u64, named_input: bool) -> bool {
+        match &self.hash {
+            Some(hash) => u64::from_str_radix(hash, 16) == Ok(input_hash),
+            None => named_input,
+        }
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+/// Content of an `answers/NN.toml` file: the confirmed answers of a day, by
+/// input. The `input` table holds the answers for `inputs/NN.txt` and the
+/// `example` table the answers for `examples/NN.txt`. A table can record the
+/// hash of its input, so its answers are also checked for that input when read
+/// from another path, and never for a different input.
+///
+/// ```toml
+/// [input]
+/// part1 = 1150
+/// part2 = "4174379265"
+/// hash = "af63dc4c8601ec8c"
+///
+/// [example]
+/// part1 = 3
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct AnswerFile {
+    inputs: BTreeMap<String, Answers>,
+}
+
+pub const INPUT: &str = "input";
+pub const EXAMPLE: &str = "example";
+
+impl AnswerFile {
+    pub fn parse(content: &str) -> Result<AnswerFile, toml::de::Error> {
+        toml::from_str(content)
+    }
+
+    /// Loads the answers file at `path`. A missing file has no answers.
+    pub fn load(path: &Path) -> Result<AnswerFile, AnswersError> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                return Ok(AnswerFile::default());
+            }
+            Err(error) => {
+                return Err(AnswersError {
+                    path: path.to_path_buf(),
+                    kind: AnswersErrorKind::Io(error),
+                });
+            }
+        };
+        AnswerFile::parse(&content).map_err(|error| AnswersError {
+            path: path.to_path_buf(),
+            kind: AnswersErrorKind::Parse(error),
+        })
+    }
+
+    /// Returns the answers for the input with the given name, `input` or
+    /// `example`.
+    pub fn get(&self, input: &str) -> Option<&Answers> {
+        self.inputs.get(input)
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersErrorKind {
+    Io(io::Error),
+    Parse(toml::de::Error),
+}
+
+#[derive(Debug)]
+pub struct AnswersError {
+    pub path: PathBuf,
+    pub kind: AnswersErrorKind,
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            AnswersErrorKind::Io(error) => {
+                write!(f, "Unable to read {}: {}", self.path.display(), error)
+            }
+            AnswersErrorKind::Parse(error) => {
+                write!(f, "Invalid answers file {}: {}", self.path.display(), error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+/// Path of the answers of a day: `answers/NN.toml`.
+pub fn default_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("answers/{:02}.toml", day))
+}
+
+/// Result of the comparison of an answer with the confirmed one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect { expected: String },
+    Unknown,
+}
+
+pub fn check(expected: Option<&str>, actual: &str) -> Verdict {
+    match expected {
+        Some(expected) if expected == actual => Verdict::Correct,
+        Some(expected) => Verdict::Incorrect {
+            expected: expected.to_string(),
+        },
+        None => Verdict::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{input, runner};
+
+    #[test]
+    fn test_parse() {
+        let file = AnswerFile::parse(
+            "[input]\npart1 = 1150\npart2 = \"4174379265\"\n\n[example]\npart2 = 6\n",
+        )
+        .unwrap();
+
+        let answers = file.get(INPUT).unwrap();
+        assert_eq!(answers.get(Part::One), Some("1150"));
+        assert_eq!(answers.get(Part::Two), Some("4174379265"));
+
+        let answers = file.get(EXAMPLE).unwrap();
+        assert_eq!(answers.get(Part::One), None);
+        assert_eq!(answers.get(Part::Two), Some("6"));
+    }
+
+    #[test]
+    fn test_holds_for() {
+        let file = AnswerFile::parse(
+            "[input]\npart1 = 1\nhash = \"00000000000000ab\"\n\n[example]\npart1 = 2\n",
+        )
+        .unwrap();
+        let answers = file.get(INPUT).unwrap();
+        assert!(answers.holds_for(0xab, true));
+        assert!(answers.holds_for(0xab, false));
+        assert!(!answers.holds_for(0xac, true));
+
+        let answers = file.get(EXAMPLE).unwrap();
+        assert!(answers.holds_for(0xac, true));
+        assert!(!answers.holds_for(0xac, false));
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(check(Some("3"), "3"), Verdict::Correct);
+        assert_eq!(
+            check(Some("3"), "4"),
+            Verdict::Incorrect {
+                expected: "3".to_string()
+            }
+        );
+        assert_eq!(check(None, "4"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_examples_match_answers() {
+        for day in crate::days() {
+            let path = Path::new(env!("CARGO_MANIFEST_DIR"));
+            let file = AnswerFile::load(&path.join(default_path(day.number()))).unwrap();
+            let Some(answers) = file.get(EXAMPLE) else {
+                continue;
+            };
+            let example =
+                std::fs::read_to_string(path.join(input::example_path(day.number()))).unwrap();
+            let report = runner::run(day, &example, &Part::BOTH).unwrap();
+            for part in &report.parts {
+                if let Verdict::Incorrect { expected } = check(answers.get(part.part), &part.answer)
+                {
+                    panic!(
+                        "Day {} part {}: expected {}, got {}",
+                        day.number(),
+                        part.part,
+                        expected,
+                        part.answer
+                    );
+                }
+            }
+        }
+    }
+}
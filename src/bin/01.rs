@@ -1,127 +1,134 @@
-#[derive(Debug)]
-enum Direction {
-    Left,
-    Right,
+use std::{collections::BTreeMap, process::ExitCode};
+
+use aoc2025::{
+    Solution, cli,
+    days::day01::{self, Count, Day01, Dial, Stats, Step, Stream, StreamError},
+    output::{self, Format},
+};
+use serde::Serialize;
+
+/// Stats of a dial of a lock, or of the whole lock without a dial name.
+#[derive(Serialize)]
+struct DialRecord<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dial: Option<&'a str>,
+    #[serde(flatten)]
+    stats: Stats,
 }
 
-#[derive(Debug)]
-struct Instruction {
-    direction: Direction,
-    distance: i16,
+/// Prints the rotations of part 2 with the number of times each one points
+/// at 0.
+fn print_trace<'a>(steps: impl Iterator<Item = Step<'a>>, format: Format) {
+    if format == Format::Text {
+        println!(
+            "{:>5} | {:>11} | {:>6} | {:>5} | {:>5} | {:>6}",
+            "Step", "Instruction", "Before", "After", "Zeros", "Total"
+        );
+        println!("{}", "-".repeat(54));
+    }
+    for (index, step) in steps.enumerate() {
+        match format {
+            Format::Text => println!(
+                "{:>5} | {:>11} | {:>6} | {:>5} | {:>5} | {:>6}",
+                index + 1,
+                step.instruction.to_string(),
+                step.before,
+                step.after,
+                step.count,
+                step.total
+            ),
+            Format::Json => output::print_json(&step),
+        }
+    }
 }
 
-fn parse_input(input: &str) -> Vec<Instruction> {
-    input
-        .lines()
-        .map(|line| {
-            let direction = match line.chars().next().expect("Line should never be empty") {
-                'L' => Direction::Left,
-                'R' => Direction::Right,
-                _ => panic!("Invalid direction"),
-            };
-            let distance: i16 = line[1..].parse().expect("Invalid distance");
-            Instruction {
-                direction,
-                distance,
-            }
+/// Prints the stats of every dial of a lock, then of the whole lock.
+fn print_lock(stats: &BTreeMap<String, Stats>, format: Format) {
+    let total: Stats = stats.values().copied().sum();
+    let records = stats
+        .iter()
+        .map(|(dial, &stats)| DialRecord {
+            dial: Some(dial),
+            stats,
         })
-        .collect()
-}
-
-fn part1(data: &Vec<Instruction>) -> i16 {
-    let mut result = 0;
-    let mut dial: i16 = 50;
-    for instruction in data {
-        match instruction.direction {
-            Direction::Left => dial = (dial - instruction.distance).rem_euclid(100),
-            Direction::Right => dial = (dial + instruction.distance).rem_euclid(100),
-        };
-        if dial == 0 {
-            result += 1;
+        .chain([DialRecord {
+            dial: None,
+            stats: total,
+        }]);
+
+    if format == Format::Text {
+        println!("{:>8} | {:>10} | {:>10}", "Dial", "Landings", "Crossings");
+        println!("{}", "-".repeat(34));
+    }
+    for record in records {
+        match format {
+            Format::Text => println!(
+                "{:>8} | {:>10} | {:>10}",
+                record.dial.unwrap_or("Total"),
+                record.stats.landings,
+                record.stats.crossings
+            ),
+            Format::Json => output::print_json(&record),
         }
     }
-    result
 }
 
-fn part2(data: &Vec<Instruction>) -> i16 {
-    let mut result = 0;
-    let mut dial: i16 = 50;
-    for instruction in data {
-        match instruction.direction {
-            Direction::Left => {
-                result += ((100 - dial).rem_euclid(100) + instruction.distance) / 100;
-                dial = (dial - instruction.distance).rem_euclid(100)
+/// Prints the counts of both parts after every instruction, reading the
+/// instructions as they come.
+fn print_stream(args: &cli::Args) -> ExitCode {
+    let reader = match args.source.open() {
+        Ok(reader) => reader,
+        Err(error) => {
+            cli::print_error(Day01::DAY, args.format, &error);
+            return ExitCode::FAILURE;
+        }
+    };
+    for progress in Stream::new(reader) {
+        match progress {
+            Ok(progress) => match args.format {
+                Format::Text => println!(
+                    "Line {}: part 1 = {}, part 2 = {}",
+                    progress.line, progress.part1, progress.part2
+                ),
+                Format::Json => output::print_json(&progress),
+            },
+            Err(StreamError::Io(error)) => {
+                cli::print_error(Day01::DAY, args.format, &error);
+                return ExitCode::FAILURE;
             }
-            Direction::Right => {
-                result += (dial + instruction.distance) / 100;
-                dial = (dial + instruction.distance).rem_euclid(100)
+            Err(StreamError::Parse(error)) => {
+                cli::print_parse_error(args.format, &error);
+                return ExitCode::FAILURE;
             }
-        };
-    }
-    if dial == 0 {
-        result += 1;
+        }
     }
-    result
+    ExitCode::SUCCESS
 }
 
-fn main() {
-    let input = include_str!("../../inputs/01.txt");
-    let data = parse_input(input);
-
-    println!("Part 1 : {}", part1(&data));
-    println!("Part 2 : {}", part2(&data));
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_example_day1_part1() {
-        let input = include_str!("../../examples/01.txt");
-        let data = parse_input(input);
-        assert_eq!(part1(&data), 3);
-    }
-
-    #[test]
-    fn test_example_day1_part2() {
-        let input = include_str!("../../examples/01.txt");
-        let data = parse_input(input);
-        assert_eq!(part2(&data), 6);
+fn main() -> ExitCode {
+    let Some(args) = cli::args(Day01::DAY, &["--trace", "--lock", "--stream"]) else {
+        return ExitCode::FAILURE;
+    };
+    if args.flag("--stream") {
+        return print_stream(&args);
     }
-
-    fn l(dial: i16, dist: i16) -> i16 {
-        ((100 - dial).rem_euclid(100) + dist) / 100
+    if args.flag("--lock") {
+        let Some(input) = cli::load_with(Day01::DAY, &args, day01::parse_keyed) else {
+            return ExitCode::FAILURE;
+        };
+        print_lock(&input.lock.stats(&input.instructions), args.format);
+        return ExitCode::SUCCESS;
     }
-
-    fn r(dial: i16, dist: i16) -> i16 {
-        (dial + dist) / 100
+    if !args.flag("--trace") {
+        return cli::solve(&Day01, &args);
     }
 
-    #[test]
-    fn my_test() {
-        assert_eq!(l(0, 1), 0);
-        assert_eq!(l(0, 99), 0);
-        assert_eq!(l(0, 100), 1);
-
-        assert_eq!(l(10, 9), 0);
-        assert_eq!(l(10, 10), 1);
-        assert_eq!(l(10, 11), 1);
-
-        assert_eq!(l(10, 109), 1);
-        assert_eq!(l(10, 110), 2);
-        assert_eq!(l(10, 111), 2);
-
-        assert_eq!(r(10, 89), 0);
-        assert_eq!(r(10, 90), 1);
-        assert_eq!(r(10, 91), 1);
-
-        assert_eq!(r(10, 189), 1);
-        assert_eq!(r(10, 190), 2);
-        assert_eq!(r(10, 191), 2);
-
-        assert_eq!(r(0, 1), 0);
-        assert_eq!(r(0, 99), 0);
-        assert_eq!(r(0, 100), 1);
-    }
+    let Some(data) = cli::load::<Day01>(&args) else {
+        return ExitCode::FAILURE;
+    };
+    print_trace(
+        Dial::default().counting(Count::PassThrough).trace(&data),
+        args.format,
+    );
+    ExitCode::SUCCESS
 }
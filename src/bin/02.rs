@@ -1,142 +1,101 @@
-fn parse_input(input: &str) -> Vec<(u64, u64)> {
-    input
-        .split(',')
-        .map(|range| range.split('-'))
-        .map(|mut bounds| {
-            let start = bounds.next().unwrap().parse::<u64>().unwrap();
-            let end = bounds.next().unwrap().parse::<u64>().unwrap();
-            (start, end)
-        })
-        .collect()
+use std::process::ExitCode;
+
+use aoc2025::{
+    Solution, cli,
+    days::day02::{self, Base, Day02, InvalidId},
+    output::{self, Format},
+    parse::ParseError,
+};
+use serde::Serialize;
+
+/// An invalid ID of a range, for one part.
+#[derive(Serialize)]
+struct IdRecord {
+    begin: u64,
+    end: u64,
+    part: u8,
+    #[serde(flatten)]
+    id: InvalidId,
 }
 
-// On coupe le nombre en deux parties : 12 et 34...
-// la partie de gauche devient notre curseur. Le première nombre qu'on va considérer le curseur doublés
-// Si la partie de gauche est plus petite que la partie de droite ajouter 1 au curseur
+/// Day 2 with the overlapping ranges merged, so every ID counts once.
+struct Merged;
 
-fn part1_single_range(begin_range: u64, end_range: u64) -> u64 {
-    let num_digits = begin_range.ilog10() + 1;
-    let divisor = 10u64.pow((num_digits + 1) / 2);
-    let begin_right = begin_range % divisor;
-    let mut begin_left = begin_range / divisor;
-    // add a one if odd number of digits
-    begin_left += (num_digits % 2 * 10u32.pow(num_digits / 2)) as u64;
-    // Start is left part + 1 if left < right
-    let start = if num_digits % 2 == 0 {
-        if begin_left < begin_right {
-            begin_left + 1
-        } else {
-            begin_left
-        }
-    } else {
-        10u64.pow(num_digits / 2)
-    };
+impl Solution for Merged {
+    const DAY: u8 = Day02::DAY;
 
-    let num_digits = end_range.ilog10() + 1;
-    let divisor = 10u64.pow((num_digits + 1) / 2);
-    let end_right = end_range % divisor;
-    let end_left = end_range / divisor;
-    let end = if num_digits % 2 == 0 {
-        if end_right < end_left {
-            end_left - 1
-        } else {
-            end_left
-        }
-    } else {
-        10u64.pow(num_digits / 2) - 1
-    };
+    type Data = Vec<(u64, u64)>;
+    type Answer1 = u128;
+    type Answer2 = u128;
 
-    (start..=end)
-        .map(|x| double(x))
-        .fold(0u64, |acc, x| acc + x)
-}
-
-fn part1(data: &Vec<(u64, u64)>) -> u64 {
-    data.into_iter()
-        .map(|(start, end)| part1_single_range(*start, *end))
-        .fold(0u64, |acc, x| acc + x)
-}
-
-fn part2_is_repeating(x: u64) -> bool {
-    let s = x.to_string();
-
-    for len in 1..=s.len() / 2 {
-        if s.len() % len != 0 {
-            continue;
-        }
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        day02::parse_merged(input)
+    }
 
-        let mut chunks = s.as_bytes().chunks(len);
-        let first_chunk = chunks.next().unwrap();
-        if chunks.all(|chunk| first_chunk == chunk) {
-            return true;
-        }
+    fn part1(data: &Self::Data) -> Self::Answer1 {
+        Day02::part1(data)
     }
-    false
-}
 
-fn part2_range_sum_invalid(begin_range: u64, end_range: u64) -> u64 {
-    (begin_range..=end_range)
-        .filter(|x| part2_is_repeating(*x))
-        .fold(0u64, |acc, x| acc + x)
+    fn part2(data: &Self::Data) -> Self::Answer2 {
+        Day02::part2(data)
+    }
 }
 
-fn part2(data: &Vec<(u64, u64)>) -> u64 {
-    data.iter()
-        .map(|(start, end)| part2_range_sum_invalid(*start, *end))
-        .fold(0u64, |acc, x| acc + x)
+/// Prints the invalid IDs of both parts, range by range.
+fn print_ids(ranges: &[(u64, u64)], format: Format) {
+    if format == Format::Text {
+        println!(
+            "{:>23} | {:>4} | {:>12} | {:>6} | {:>11}",
+            "Range", "Part", "ID", "Block", "Repetitions"
+        );
+        println!("{}", "-".repeat(68));
+    }
+    for &(begin, end) in ranges {
+        let (first, last) = (begin as u128, end as u128);
+        let part1 = day02::doubled_ids(Base::DECIMAL, first, last).map(|id| (1, id));
+        let part2 = day02::repeated_ids(Base::DECIMAL, first, last).map(|id| (2, id));
+        for (part, id) in part1.chain(part2) {
+            match format {
+                Format::Text => println!(
+                    "{:>23} | {:>4} | {:>12} | {:>6} | {:>11}",
+                    format!("{}-{}", begin, end),
+                    part,
+                    id.id,
+                    id.block,
+                    id.repetitions
+                ),
+                Format::Json => output::print_json(&IdRecord {
+                    begin,
+                    end,
+                    part,
+                    id,
+                }),
+            }
+        }
+    }
 }
 
-fn double(x: u64) -> u64 {
-    let num_digits = x.ilog10() + 1;
-    let mul = 10u64.pow(num_digits);
-    x * mul + x
-}
+fn main() -> ExitCode {
+    let Some(args) = cli::args(Day02::DAY, &["--ids", "--merge"]) else {
+        return ExitCode::FAILURE;
+    };
+    let merge = args.flag("--merge");
+    if !args.flag("--ids") {
+        return if merge {
+            cli::solve(&Merged, &args)
+        } else {
+            cli::solve(&Day02, &args)
+        };
+    }
 
-fn main() {
-    let input = include_str!("../../inputs/02.txt");
-    let data = parse_input(input);
-    println!("Part 1: {}", part1(&data));
-    println!("Part 2: {}", part2(&data));
+    let data = if merge {
+        cli::load::<Merged>(&args)
+    } else {
+        cli::load::<Day02>(&args)
+    };
+    let Some(data) = data else {
+        return ExitCode::FAILURE;
+    };
+    print_ids(&data, args.format);
+    ExitCode::SUCCESS
 }
-
-// #[cfg(test)]
-// mod tests {
-//     use super::*;
-
-//     #[test]
-//     fn test_double() {
-//         assert_eq!(double(12), 1212);
-//         assert_eq!(double(123), 123123);
-//         assert_eq!(double(1), 11);
-//     }
-
-//     #[test]
-//     fn test_example_part1() {
-//         let input = include_str!("../../examples/02.txt");
-//         let data = parse_input(input);
-//         assert_eq!(part1(&data), 1227775554);
-//     }
-
-//     #[test]
-//     fn test_example_part2() {
-//         let input = include_str!("../../examples/02.txt");
-//         let data = parse_input(input);
-//         assert_eq!(part2(&data), 4174379265);
-//     }
-
-//     #[test]
-//     fn test_part2_range_sum_invalid() {
-//         assert_eq!(part2_range_sum_invalid(11, 22), 33);
-//         assert_eq!(part2_range_sum_invalid(95, 115), 99 + 111);
-
-//         assert_eq!(part2_range_sum_invalid(998, 1012), 999 + 1010);
-//         assert_eq!(part2_range_sum_invalid(1188511880, 1188511890), 1188511885);
-//         assert_eq!(part2_range_sum_invalid(222220, 222224), 222222);
-//         assert_eq!(part2_range_sum_invalid(1698522, 1698528), 0);
-//         assert_eq!(part2_range_sum_invalid(446443, 446449), 446446);
-//         assert_eq!(part2_range_sum_invalid(38593856, 38593862), 38593859);
-//         assert_eq!(part2_range_sum_invalid(565653, 565659), 565656);
-//         assert_eq!(part2_range_sum_invalid(824824821, 824824827), 824824824);
-//         assert_eq!(part2_range_sum_invalid(1212121218, 2121212124), 2121212121);
-//     }
-// }
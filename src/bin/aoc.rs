@@ -0,0 +1,336 @@
+use std::{process::ExitCode, time::Duration};
+
+use aoc2025::{
+    answers::{self, AnswerFile, Verdict},
+    cli, generate,
+    input::{self, Source},
+    output::{self, ErrorRecord, Format, PartRecord},
+    runner::{self, DayReport, Part},
+};
+
+const USAGE: &str = "Usage: aoc <run|test|bench|verify|generate> [DAYS] [OPTIONS]
+
+Commands:
+  run       Solve the days on their puzzle input
+  test      Solve the days on the example from examples/NN.txt
+  bench     Solve the days several times and report the average timings
+  verify    Solve the days and compare with the answers from answers/NN.toml
+  generate  Print a random input for a single day
+
+DAYS is `all` (default), a day (7), a range (1-5) or a list (1,3,5-7).
+
+Options:
+  --part <1|2>        Only solve the given part
+  --input <PATH>      Read the input from PATH (- for stdin), single day only
+  --example           Read the input from examples/NN.txt
+  --format <FORMAT>   Print a table (text, default) or one JSON object per part (json)
+  --iterations <N>    Number of runs averaged by bench (default 10)
+  --size <N>          Size of the generated input, its meaning depends on the day (default 100)
+  --seed <N>          Seed of the generated input (default 0)";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Run,
+    Test,
+    Bench,
+    Verify,
+    Generate,
+}
+
+#[derive(Debug)]
+struct Options {
+    command: Command,
+    days: Vec<u8>,
+    parts: Vec<Part>,
+    input: Option<String>,
+    example: bool,
+    format: Format,
+    iterations: u32,
+    size: usize,
+    seed: u64,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut args = args.iter();
+    let command = match args.next().map(String::as_str) {
+        Some("run") => Command::Run,
+        Some("test") => Command::Test,
+        Some("bench") => Command::Bench,
+        Some("verify") => Command::Verify,
+        Some("generate") => Command::Generate,
+        Some(command) => return Err(format!("Unknown command: {}", command)),
+        None => return Err("Missing command".to_string()),
+    };
+
+    let mut options = Options {
+        command,
+        days: runner::parse_days("all")?,
+        parts: Part::BOTH.to_vec(),
+        input: None,
+        example: command == Command::Test,
+        format: Format::Text,
+        iterations: 10,
+        size: 100,
+        seed: 0,
+    };
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "--part" => {
+                options.parts = match value()?.as_str() {
+                    "1" => vec![Part::One],
+                    "2" => vec![Part::Two],
+                    part => return Err(format!("Invalid part: {}", part)),
+                }
+            }
+            "--input" => options.input = Some(value()?.clone()),
+            "--example" => options.example = true,
+            "--format" => options.format = value()?.parse()?,
+            "--iterations" => {
+                options.iterations = value()?
+                    .parse()
+                    .ok()
+                    .filter(|&iterations| iterations > 0)
+                    .ok_or_else(|| "Invalid number of iterations".to_string())?
+            }
+            "--size" => options.size = value()?.parse().map_err(|_| "Invalid size".to_string())?,
+            "--seed" => options.seed = value()?.parse().map_err(|_| "Invalid seed".to_string())?,
+            days if !days.starts_with("--") => options.days = runner::parse_days(days)?,
+            option => return Err(format!("Unknown option: {}", option)),
+        }
+    }
+
+    if options.input.is_some() && options.example {
+        return Err("--input and --example can't be used together".to_string());
+    }
+    if options.input.is_some() && options.days.len() != 1 {
+        return Err("--input can only be used with a single day".to_string());
+    }
+    if options.command == Command::Generate && options.days.len() != 1 {
+        return Err("generate needs a single day".to_string());
+    }
+
+    Ok(options)
+}
+
+fn source(options: &Options, day: u8) -> Source {
+    if options.example {
+        Source::Path(input::example_path(day))
+    } else {
+        Source::from_arg(day, options.input.as_deref())
+    }
+}
+
+/// Averages the timings of several runs of the same day.
+fn average(reports: &[DayReport]) -> DayReport {
+    let count = reports.len() as u32;
+    let mut average = reports.last().expect("At least one run").clone();
+    average.parse = reports.iter().map(|report| report.parse).sum::<Duration>() / count;
+    for part in average.parts.iter_mut() {
+        part.elapsed = reports
+            .iter()
+            .filter_map(|report| report.part(part.part))
+            .map(|report| report.elapsed)
+            .sum::<Duration>()
+            / count;
+    }
+    average
+}
+
+fn print_table(reports: &[DayReport]) {
+    let answer = |report: &DayReport, part| {
+        report
+            .part(part)
+            .map_or("-".to_string(), |part| part.answer.clone())
+    };
+    let elapsed = |report: &DayReport, part| {
+        report
+            .part(part)
+            .map_or("-".to_string(), |part| format!("{:.2?}", part.elapsed))
+    };
+
+    println!(
+        "{:>3} | {:>16} | {:>16} | {:>10} | {:>10} | {:>10}",
+        "Day", "Part 1", "Part 2", "Parse", "Part 1", "Part 2"
+    );
+    println!("{}", "-".repeat(82));
+    for report in reports {
+        println!(
+            "{:>3} | {:>16} | {:>16} | {:>10} | {:>10} | {:>10}",
+            report.day,
+            answer(report, Part::One),
+            answer(report, Part::Two),
+            format!("{:.2?}", report.parse),
+            elapsed(report, Part::One),
+            elapsed(report, Part::Two),
+        );
+    }
+    println!("{}", "-".repeat(82));
+    println!(
+        "Total: {:.2?}",
+        reports.iter().map(DayReport::total).sum::<Duration>()
+    );
+}
+
+fn print_json(reports: &[DayReport]) {
+    for report in reports {
+        for part in &report.parts {
+            output::print_json(&PartRecord::new(report, part));
+        }
+    }
+}
+
+/// Compares the answers with the confirmed ones and prints the differences.
+/// `named_input` tells if the input is the one the answers are named after,
+/// rather than a path given with `--input`. Returns false if an answer doesn't
+/// match.
+fn print_verification(
+    reports: &[(DayReport, AnswerFile)],
+    input: &str,
+    named_input: bool,
+    format: Format,
+) -> bool {
+    let mut success = true;
+    if format == Format::Text {
+        println!(
+            "{:>3} | {:>4} | {:>16} | {:>16} | Status",
+            "Day", "Part", "Answer", "Expected"
+        );
+        println!("{}", "-".repeat(60));
+    }
+    for (report, file) in reports {
+        for part in &report.parts {
+            let expected = file
+                .get(input)
+                .filter(|answers| answers.holds_for(report.input_hash, named_input))
+                .and_then(|answers| answers.get(part.part));
+            let verdict = answers::check(expected, &part.answer);
+            let status = match verdict {
+                Verdict::Correct => "ok",
+                Verdict::Incorrect { .. } => {
+                    success = false;
+                    "MISMATCH"
+                }
+                Verdict::Unknown => "unknown",
+            };
+            if format == Format::Json {
+                output::print_json(&PartRecord::new(report, part).with_verdict(expected, &verdict));
+                continue;
+            }
+            println!(
+                "{:>3} | {:>4} | {:>16} | {:>16} | {}",
+                report.day,
+                part.part,
+                part.answer,
+                expected.unwrap_or("-"),
+                status
+            );
+        }
+    }
+    success
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if options.command == Command::Generate {
+        return match generate::generate(options.days[0], options.size, options.seed) {
+            Ok(input) => {
+                print!("{}", input);
+                ExitCode::SUCCESS
+            }
+            Err(error) => {
+                cli::print_error(options.days[0], options.format, &error);
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let mut status = ExitCode::SUCCESS;
+    let mut reports = Vec::new();
+    let mut verifications = Vec::new();
+    for &number in &options.days {
+        let Some(day) = aoc2025::day(number) else {
+            let error = format!("Day {} is not implemented", number);
+            cli::print_error(number, options.format, &error);
+            status = ExitCode::FAILURE;
+            continue;
+        };
+
+        let input = match source(&options, number).read() {
+            Ok(input) => input,
+            Err(error) => {
+                match options.format {
+                    Format::Text => eprintln!("Day {}: {}", number, error),
+                    Format::Json => output::print_json(&ErrorRecord::new(number, &error)),
+                }
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        let report = match runner::run(day, &input, &options.parts) {
+            Ok(report) => report,
+            Err(error) => {
+                match options.format {
+                    Format::Text => eprintln!("{}", error.render()),
+                    Format::Json => output::print_json(&ErrorRecord::from(&error)),
+                }
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        match options.command {
+            Command::Run | Command::Test => reports.push(report),
+            Command::Bench => {
+                let mut runs = vec![report];
+                runs.extend((1..options.iterations).map(|_| {
+                    runner::run(day, &input, &options.parts).expect("The input was already parsed")
+                }));
+                reports.push(average(&runs));
+            }
+            Command::Generate => unreachable!("Inputs are generated before solving"),
+            Command::Verify => match AnswerFile::load(&answers::default_path(number)) {
+                Ok(file) => verifications.push((report, file)),
+                Err(error) => {
+                    match options.format {
+                        Format::Text => eprintln!("Day {}: {}", number, error),
+                        Format::Json => output::print_json(&ErrorRecord::new(number, &error)),
+                    }
+                    status = ExitCode::FAILURE;
+                }
+            },
+        }
+    }
+
+    if !reports.is_empty() {
+        match options.format {
+            Format::Text => print_table(&reports),
+            Format::Json => print_json(&reports),
+        }
+    }
+    if !verifications.is_empty() {
+        let input = if options.example {
+            answers::EXAMPLE
+        } else {
+            answers::INPUT
+        };
+        let named_input = options.input.is_none();
+        if !print_verification(&verifications, input, named_input, options.format) {
+            status = ExitCode::FAILURE;
+        }
+    }
+    status
+}
@@ -0,0 +1,198 @@
+//! Command line of the binaries solving a single day:
+//!
+//! ```text
+//! NN [INPUT] [--format <text|json>] [FLAGS]
+//! ```
+//!
+//! `INPUT` is the path of the input, `-` for stdin, and `inputs/NN.txt` by
+//! default. Some days accept extra flags, like `--trace` for day 1.
+
+use std::process::ExitCode;
+
+use crate::{
+    Day, Solution,
+    input::Source,
+    output::{self, ErrorRecord, Format, PartRecord},
+    parse::ParseError,
+    runner::{self, Part},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Args {
+    pub source: Source,
+    pub format: Format,
+    /// The day specific flags that were given.
+    pub flags: Vec<String>,
+}
+
+impl Args {
+    /// Parses the arguments of the binary of a day, without the program name.
+    /// `flags` are the day specific flags accepted besides the common options.
+    pub fn parse(day: u8, args: &[String], flags: &[&str]) -> Result<Args, String> {
+        let mut input = None;
+        let mut format = Format::Text;
+        let mut given = Vec::new();
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--format" => {
+                    format = args
+                        .next()
+                        .ok_or_else(|| "Missing value for --format".to_string())?
+                        .parse()?
+                }
+                flag if flags.contains(&flag) => given.push(flag.to_string()),
+                option if option.starts_with("--") => {
+                    return Err(format!("Unknown option: {}", option));
+                }
+                path if input.is_none() => input = Some(path),
+                path => return Err(format!("Unexpected argument: {}", path)),
+            }
+        }
+
+        Ok(Args {
+            source: Source::from_arg(day, input),
+            format,
+            flags: given,
+        })
+    }
+
+    pub fn flag(&self, flag: &str) -> bool {
+        self.flags.iter().any(|given| given == flag)
+    }
+}
+
+/// Parses the command line of the binary of a day. Prints the error and the
+/// usage if the arguments are invalid.
+pub fn args(day: u8, flags: &[&str]) -> Option<Args> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match Args::parse(day, &args, flags) {
+        Ok(args) => Some(args),
+        Err(error) => {
+            let flags: String = flags.iter().map(|flag| format!(" [{}]", flag)).collect();
+            eprintln!(
+                "{}\n\nUsage: {:02} [INPUT] [--format <text|json>]{}",
+                error, day, flags
+            );
+            None
+        }
+    }
+}
+
+/// Solves both parts of a day on the input given on the command line.
+pub fn main(day: &dyn Day) -> ExitCode {
+    match args(day.number(), &[]) {
+        Some(args) => solve(day, &args),
+        None => ExitCode::FAILURE,
+    }
+}
+
+/// Prints an error preventing a day from being solved.
+pub fn print_error(day: u8, format: Format, error: &dyn std::fmt::Display) {
+    match format {
+        Format::Text => eprintln!("{}", error),
+        Format::Json => output::print_json(&ErrorRecord::new(day, error)),
+    }
+}
+
+/// Prints a parse error, with a diagram locating it in the input for text.
+pub fn print_parse_error(format: Format, error: &ParseError) {
+    match format {
+        Format::Text => eprintln!("{}", error.render()),
+        Format::Json => output::print_json(&ErrorRecord::from(error)),
+    }
+}
+
+/// Reads the input, printing the errors in the requested format.
+fn read(day: u8, args: &Args) -> Option<String> {
+    args.source
+        .read()
+        .inspect_err(|error| print_error(day, args.format, error))
+        .ok()
+}
+
+/// Reads and parses the input of a day, printing the errors in the requested
+/// format.
+pub fn load<S: Solution>(args: &Args) -> Option<S::Data> {
+    load_with(S::DAY, args, S::parse)
+}
+
+/// Reads the input of a day and parses it with `parse`, for the inputs that
+/// are not puzzle inputs.
+pub fn load_with<T, F>(day: u8, args: &Args, parse: F) -> Option<T>
+where
+    F: FnOnce(&str) -> Result<T, ParseError>,
+{
+    let input = read(day, args)?;
+    parse(&input)
+        .inspect_err(|error| print_parse_error(args.format, error))
+        .ok()
+}
+
+/// Solves both parts of a day and prints the answers.
+pub fn solve(day: &dyn Day, args: &Args) -> ExitCode {
+    let Some(input) = read(day.number(), args) else {
+        return ExitCode::FAILURE;
+    };
+
+    let report = match runner::run(day, &input, &Part::BOTH) {
+        Ok(report) => report,
+        Err(error) => {
+            print_parse_error(args.format, &error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    for part in &report.parts {
+        match args.format {
+            Format::Text => println!("Part {}: {}", part.part, part.answer),
+            Format::Json => output::print_json(&PartRecord::new(&report, part)),
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<Args, String> {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        Args::parse(7, &args, &["--trace"])
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            args(&[]),
+            Ok(Args {
+                source: Source::Path(PathBuf::from("inputs/07.txt")),
+                format: Format::Text,
+                flags: Vec::new(),
+            })
+        );
+        assert_eq!(
+            args(&["--format", "json", "-"]),
+            Ok(Args {
+                source: Source::Stdin,
+                format: Format::Json,
+                flags: Vec::new(),
+            })
+        );
+        assert!(args(&["--format"]).is_err());
+        assert!(args(&["--format", "xml"]).is_err());
+        assert!(args(&["a.txt", "b.txt"]).is_err());
+        assert!(args(&["--verbose"]).is_err());
+    }
+
+    #[test]
+    fn test_flags() {
+        let args = args(&["--trace", "a.txt"]).unwrap();
+        assert!(args.flag("--trace"));
+        assert!(!args.flag("--verbose"));
+        assert_eq!(args.source, Source::Path(PathBuf::from("a.txt")));
+    }
+}
@@ -0,0 +1,1032 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    io::{self, BufRead},
+    iter::Sum,
+    ops::Add,
+};
+
+use serde::Serialize;
+
+use crate::{
+    Solution,
+    parse::{ParseError, parse_number},
+};
+
+pub struct Day01;
+
+/// Number of positions of the dial of the puzzle.
+const SIZE: u64 = 100;
+/// Position of the dial of the puzzle before the first instruction.
+const START: u64 = 50;
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct Instruction {
+    direction: Direction,
+    distance: u64,
+}
+
+impl Instruction {
+    /// Creates an instruction turning the dial `distance` clicks towards
+    /// `direction`.
+    pub fn new(direction: Direction, distance: u64) -> Instruction {
+        Instruction {
+            direction,
+            distance,
+        }
+    }
+
+    pub fn direction(&self) -> &Direction {
+        &self.direction
+    }
+
+    pub fn distance(&self) -> u64 {
+        self.distance
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let direction = match self.direction {
+            Direction::Left => 'L',
+            Direction::Right => 'R',
+        };
+        write!(f, "{}{}", direction, self.distance)
+    }
+}
+
+/// An instruction of a lock with several dials, addressed to one of them.
+#[derive(Debug, PartialEq, Eq)]
+pub struct KeyedInstruction {
+    pub dial: String,
+    pub instruction: Instruction,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    input
+        .lines()
+        .map(|line| parse_instruction(input, line))
+        .collect()
+}
+
+/// The dials declared in the input of a lock, and the instructions addressed
+/// to them.
+#[derive(Debug)]
+pub struct LockInput {
+    pub lock: Lock,
+    pub instructions: Vec<KeyedInstruction>,
+}
+
+/// Parses the input of a lock with several dials. Lines like `A:L68` turn a
+/// dial, and lines like `A=100@50` declare a dial of 100 positions starting at
+/// 50, optionally followed by its targets like `A=100@50#0,25`. The dials that
+/// aren't declared are the dial of the puzzle.
+pub fn parse_keyed(input: &str) -> Result<LockInput, ParseError> {
+    let mut lock = Lock::new();
+    let mut instructions: Vec<KeyedInstruction> = Vec::new();
+    for line in input.lines() {
+        if let Some((dial, declaration)) = line.split_once('=') {
+            let name = parse_dial_name(input, dial)?;
+            let message = if lock.dials.contains_key(name) {
+                Some(format!("Dial '{}' is already declared", name))
+            } else if instructions.iter().any(|keyed| keyed.dial == name) {
+                Some(format!(
+                    "Dial '{}' is declared after its instructions",
+                    name
+                ))
+            } else {
+                None
+            };
+            if let Some(message) = message {
+                return Err(ParseError::at(Day01::DAY, input, dial, message));
+            }
+            lock = lock.with_dial(name, parse_dial(input, declaration)?);
+            continue;
+        }
+
+        let Some((dial, instruction)) = line.split_once(':') else {
+            return Err(ParseError::at(Day01::DAY, input, line, "Missing dial name"));
+        };
+        instructions.push(KeyedInstruction {
+            dial: parse_dial_name(input, dial)?.to_string(),
+            instruction: parse_instruction(input, instruction)?,
+        });
+    }
+    Ok(LockInput { lock, instructions })
+}
+
+fn parse_dial_name<'a>(input: &str, dial: &'a str) -> Result<&'a str, ParseError> {
+    if dial.is_empty() || !dial.chars().all(char::is_alphanumeric) {
+        return Err(ParseError::at(
+            Day01::DAY,
+            input,
+            dial,
+            format!("Invalid dial name '{}'", dial),
+        ));
+    }
+    Ok(dial)
+}
+
+/// Parses the declaration of a dial like `100@50` or `100@50#0,25`.
+fn parse_dial(input: &str, text: &str) -> Result<Dial, ParseError> {
+    let (dial, targets) = match text.split_once('#') {
+        Some((dial, targets)) => (dial, Some(targets)),
+        None => (text, None),
+    };
+    let Some((size, start)) = dial.split_once('@') else {
+        return Err(ParseError::at(
+            Day01::DAY,
+            input,
+            text,
+            format!("Invalid dial '{}', expected SIZE@START", text),
+        ));
+    };
+
+    let size_text = size;
+    let size: u64 = parse_number(Day01::DAY, input, size)?;
+    if size == 0 {
+        return Err(ParseError::at(
+            Day01::DAY,
+            input,
+            size_text,
+            "The dial needs at least one position",
+        ));
+    }
+    // The start and the targets must be on the dial
+    let position = |text: &str| {
+        let position: u64 = parse_number(Day01::DAY, input, text)?;
+        if position >= size {
+            return Err(ParseError::at(
+                Day01::DAY,
+                input,
+                text,
+                format!("Position {} is not on a dial of size {}", position, size),
+            ));
+        }
+        Ok(position)
+    };
+
+    let mut dial = Dial::new(size, position(start)?);
+    if let Some(targets) = targets {
+        let targets = targets
+            .split(',')
+            .map(position)
+            .collect::<Result<Vec<_>, _>>()?;
+        dial = dial.with_targets(targets);
+    }
+    Ok(dial)
+}
+
+/// Parses an instruction like `L68`, found in `input`.
+fn parse_instruction(input: &str, text: &str) -> Result<Instruction, ParseError> {
+    let mut chars = text.chars();
+    let direction = match chars.next() {
+        Some('L') => Direction::Left,
+        Some('R') => Direction::Right,
+        Some(c) => {
+            return Err(ParseError::at(
+                Day01::DAY,
+                input,
+                &text[..c.len_utf8()],
+                format!("Invalid direction '{}'", c),
+            ));
+        }
+        None => return Err(ParseError::at(Day01::DAY, input, text, "Missing direction")),
+    };
+    let distance = parse_number(Day01::DAY, input, chars.as_str())?;
+    Ok(Instruction::new(direction, distance))
+}
+
+/// How the times a dial points at a target are counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Count {
+    /// Count the rotations ending on a target.
+    LandOn,
+    /// Count every click of a rotation that points at a target.
+    PassThrough,
+}
+
+/// What happened during one rotation of a dial.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Step<'a> {
+    pub instruction: &'a Instruction,
+    /// Position of the dial before the rotation.
+    pub before: u64,
+    /// Position of the dial after the rotation.
+    pub after: u64,
+    /// Times the dial pointed at a target during the rotation, according to
+    /// its counting mode.
+    pub count: u64,
+    /// Sum of the counts of this step and the previous ones.
+    pub total: u64,
+}
+
+/// A dial numbered from 0 to `size - 1`, turned by the instructions, that
+/// counts the times it points at one of its targets.
+#[derive(Debug, Clone)]
+pub struct Dial {
+    size: u64,
+    position: u64,
+    targets: BTreeSet<u64>,
+    mode: Count,
+}
+
+impl Dial {
+    /// Creates a dial pointing at `start`, counting the rotations landing
+    /// on 0.
+    pub fn new(size: u64, start: u64) -> Dial {
+        assert!(size > 0, "The dial needs at least one position");
+        assert!((0..size).contains(&start), "The start is not on the dial");
+        Dial {
+            size,
+            position: start,
+            targets: BTreeSet::from([0]),
+            mode: Count::LandOn,
+        }
+    }
+
+    pub fn with_targets<I>(mut self, targets: I) -> Dial
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        self.targets = targets.into_iter().collect();
+        assert!(
+            self.targets
+                .iter()
+                .all(|target| (0..self.size).contains(target)),
+            "A target is not on the dial"
+        );
+        self
+    }
+
+    pub fn counting(mut self, mode: Count) -> Dial {
+        self.mode = mode;
+        self
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Turns the dial, and returns the number of times it pointed at a target
+    /// during the rotation.
+    pub fn rotate(&mut self, instruction: &Instruction) -> u64 {
+        let passes = self.passes(instruction);
+        // Only the last partial revolution moves the dial
+        let distance = instruction.distance % self.size;
+        self.position = match instruction.direction {
+            Direction::Left => self.left(self.position, distance),
+            Direction::Right => self.right(self.position, distance),
+        };
+        match self.mode {
+            Count::LandOn => self.targets.contains(&self.position) as u64,
+            Count::PassThrough => passes,
+        }
+    }
+
+    /// Number of clicks of a rotation from the current position that point at
+    /// a target.
+    fn passes(&self, instruction: &Instruction) -> u64 {
+        self.targets
+            .iter()
+            .map(|&target| self.clicks_to(target, &instruction.direction))
+            .filter(|&clicks| clicks <= instruction.distance)
+            .map(|clicks| (instruction.distance - clicks) / self.size + 1)
+            .sum()
+    }
+
+    /// Number of clicks needed to reach `target` turning in `direction`, at
+    /// least one.
+    fn clicks_to(&self, target: u64, direction: &Direction) -> u64 {
+        let clicks = match direction {
+            Direction::Left => self.left(self.position, target),
+            Direction::Right => self.left(target, self.position),
+        };
+        if clicks == 0 { self.size } else { clicks }
+    }
+
+    /// Position reached turning left by less than a revolution. Positions are
+    /// computed without intermediate sums, so they never overflow, even for
+    /// the largest dials.
+    fn left(&self, position: u64, clicks: u64) -> u64 {
+        if clicks <= position {
+            position - clicks
+        } else {
+            self.size - (clicks - position)
+        }
+    }
+
+    /// Position reached turning right by less than a revolution.
+    fn right(&self, position: u64, clicks: u64) -> u64 {
+        let room = self.size - position;
+        if clicks < room {
+            position + clicks
+        } else {
+            clicks - room
+        }
+    }
+
+    /// Follows the instructions from the current position, and returns the
+    /// total count.
+    pub fn count(&self, data: &[Instruction]) -> u64 {
+        self.trace(data).last().map_or(0, |step| step.total)
+    }
+
+    /// Follows the instructions from the current position, one step at a
+    /// time.
+    pub fn trace<'a>(&self, data: &'a [Instruction]) -> impl Iterator<Item = Step<'a>> + use<'a> {
+        let mut dial = self.clone();
+        let mut total = 0;
+        data.iter().map(move |instruction| {
+            let before = dial.position;
+            let count = dial.rotate(instruction);
+            total += count;
+            Step {
+                instruction,
+                before,
+                after: dial.position,
+                count,
+                total,
+            }
+        })
+    }
+
+    /// Returns the count of the dial for every start position, following the
+    /// instructions. It takes O(instructions × targets + size), so the dial
+    /// must be small enough to keep a count for each position.
+    pub fn counts_by_start(&self, data: &[Instruction]) -> Vec<u64> {
+        let size = usize::try_from(self.size).expect("The dial is too large");
+
+        // The dial started at `s` is always `s` positions right of the same
+        // dial started at 0, so each rotation counts for a range of starts.
+        let mut offset = self.clone();
+        offset.position = 0;
+        let mut common = 0;
+        let mut changes = vec![0i64; size + 1];
+        let mut add = |first: u64, length: u64| {
+            let (first, length) = (first as usize, length as usize);
+            changes[first] += 1;
+            if first + length <= size {
+                changes[first + length] -= 1;
+            } else {
+                changes[size] -= 1;
+                changes[0] += 1;
+                changes[first + length - size] -= 1;
+            }
+        };
+        for instruction in data {
+            let before = offset.position;
+            offset.rotate(instruction);
+            for &target in &self.targets {
+                match self.mode {
+                    Count::LandOn => add(self.left(target, offset.position), 1),
+                    Count::PassThrough => {
+                        // Full revolutions count for every start, the partial
+                        // one for the starts at most `arc` clicks away from
+                        // the target
+                        common += instruction.distance / self.size;
+                        let arc = instruction.distance % self.size;
+                        if arc > 0 {
+                            // The start pointing at the target before the rotation
+                            let from = self.left(target, before);
+                            let first = match instruction.direction {
+                                Direction::Left => self.right(from, 1),
+                                Direction::Right => self.left(from, arc),
+                            };
+                            add(first, arc);
+                        }
+                    }
+                }
+            }
+        }
+
+        changes[..size]
+            .iter()
+            .scan(0, |count, change| {
+                *count += change;
+                Some(common + *count as u64)
+            })
+            .collect()
+    }
+
+    /// Start positions from which following the instructions gives `count`,
+    /// in increasing order.
+    pub fn starts_counting(&self, data: &[Instruction], count: u64) -> Vec<u64> {
+        self.counts_by_start(data)
+            .into_iter()
+            .zip(0..)
+            .filter(|&(start_count, _)| start_count == count)
+            .map(|(_, start)| start)
+            .collect()
+    }
+}
+
+impl Default for Dial {
+    /// The dial of the puzzle.
+    fn default() -> Self {
+        Dial::new(SIZE, START)
+    }
+}
+
+/// Landings and crossings of the targets of a dial.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    /// Rotations ending on a target.
+    pub landings: u64,
+    /// Clicks pointing at a target.
+    pub crossings: u64,
+}
+
+impl Add for Stats {
+    type Output = Stats;
+
+    fn add(self, other: Stats) -> Stats {
+        Stats {
+            landings: self.landings + other.landings,
+            crossings: self.crossings + other.crossings,
+        }
+    }
+}
+
+impl Sum for Stats {
+    fn sum<I: Iterator<Item = Stats>>(iter: I) -> Stats {
+        iter.fold(Stats::default(), Add::add)
+    }
+}
+
+/// A combination lock with several named dials, each one turned by the
+/// instructions addressed to it. The dials can have different sizes, starts
+/// and targets, and are the dial of the puzzle unless configured otherwise.
+#[derive(Debug, Clone, Default)]
+pub struct Lock {
+    dials: BTreeMap<String, Dial>,
+}
+
+impl Lock {
+    pub fn new() -> Lock {
+        Lock::default()
+    }
+
+    pub fn with_dial(mut self, name: impl Into<String>, dial: Dial) -> Lock {
+        self.dials.insert(name.into(), dial);
+        self
+    }
+
+    /// Follows the instructions, and returns the stats of every dial that's
+    /// configured or addressed by an instruction. The counting mode of the
+    /// dials is ignored, since both landings and crossings are counted.
+    pub fn stats(&self, data: &[KeyedInstruction]) -> BTreeMap<String, Stats> {
+        let mut dials: BTreeMap<&str, (Dial, Dial, Stats)> = BTreeMap::new();
+        let start = |name: &str| {
+            let dial = self.dials.get(name).cloned().unwrap_or_default();
+            (
+                dial.clone().counting(Count::LandOn),
+                dial.counting(Count::PassThrough),
+                Stats::default(),
+            )
+        };
+        for name in self.dials.keys() {
+            dials.insert(name, start(name));
+        }
+        for keyed in data {
+            let (landing, crossing, stats) = dials
+                .entry(&keyed.dial)
+                .or_insert_with(|| start(&keyed.dial));
+            stats.landings += landing.rotate(&keyed.instruction);
+            stats.crossings += crossing.rotate(&keyed.instruction);
+        }
+        dials
+            .into_iter()
+            .map(|(name, (_, _, stats))| (name.to_string(), stats))
+            .collect()
+    }
+}
+
+/// Counts of both parts after the instruction on `line`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Progress {
+    pub line: usize,
+    pub part1: u64,
+    pub part2: u64,
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamError::Io(error) => write!(f, "Unable to read the instructions: {}", error),
+            StreamError::Parse(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+/// Solves both parts while reading the instructions one line at a time, so
+/// inputs of any length are solved in constant memory. Yields the counts
+/// after every instruction, and stops after the first error.
+pub struct Stream<R> {
+    reader: R,
+    buffer: String,
+    landing: Dial,
+    crossing: Dial,
+    progress: Progress,
+    done: bool,
+}
+
+impl<R: BufRead> Stream<R> {
+    pub fn new(reader: R) -> Stream<R> {
+        Stream::with_dial(reader, Dial::default())
+    }
+
+    /// Streams the instructions to the given dial instead of the puzzle's
+    /// dial. Its counting mode is ignored.
+    pub fn with_dial(reader: R, dial: Dial) -> Stream<R> {
+        Stream {
+            reader,
+            buffer: String::new(),
+            landing: dial.clone().counting(Count::LandOn),
+            crossing: dial.counting(Count::PassThrough),
+            progress: Progress::default(),
+            done: false,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Stream<R> {
+    type Item = Result<Progress, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        self.buffer.clear();
+        match self.reader.read_line(&mut self.buffer) {
+            Ok(0) => {
+                self.done = true;
+                return None;
+            }
+            Ok(_) => self.progress.line += 1,
+            Err(error) => {
+                self.done = true;
+                return Some(Err(StreamError::Io(error)));
+            }
+        }
+
+        let line = self.buffer.strip_suffix('\n').unwrap_or(&self.buffer);
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        match parse_instruction(line, line) {
+            Ok(instruction) => {
+                self.progress.part1 += self.landing.rotate(&instruction);
+                self.progress.part2 += self.crossing.rotate(&instruction);
+                Some(Ok(self.progress))
+            }
+            Err(mut error) => {
+                // The line was parsed on its own
+                error.line = self.progress.line;
+                self.done = true;
+                Some(Err(StreamError::Parse(error)))
+            }
+        }
+    }
+}
+
+fn part1(data: &[Instruction]) -> u64 {
+    Dial::default().count(data)
+}
+
+fn part2(data: &[Instruction]) -> u64 {
+    Dial::default().counting(Count::PassThrough).count(data)
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Data = Vec<Instruction>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Answer1 {
+        part1(data)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Answer2 {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::{self, Rng};
+
+    #[test]
+    fn test_example_day1_part1() {
+        let input = include_str!("../../examples/01.txt");
+        let data = parse_input(input).unwrap();
+        assert_eq!(part1(&data), 3);
+    }
+
+    #[test]
+    fn test_example_day1_part2() {
+        let input = include_str!("../../examples/01.txt");
+        let data = parse_input(input).unwrap();
+        assert_eq!(part2(&data), 6);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("L68\nX30\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.message, "Invalid direction 'X'");
+
+        let error = parse_input("L68\nR3a\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.text, "3a");
+    }
+
+    #[test]
+    fn test_trace() {
+        let input = include_str!("../../examples/01.txt");
+        let data = parse_input(input).unwrap();
+        let steps: Vec<Step> = Dial::default()
+            .counting(Count::PassThrough)
+            .trace(&data)
+            .collect();
+        assert_eq!(steps.len(), data.len());
+        assert_eq!(steps[0].instruction.to_string(), "L68");
+        assert_eq!((steps[0].before, steps[0].after), (50, 82));
+        assert_eq!((steps[0].count, steps[0].total), (1, 1));
+        assert_eq!((steps[2].before, steps[2].after), (52, 0));
+        assert_eq!(steps.last().unwrap().total, part2(&data));
+        for pair in steps.windows(2) {
+            assert_eq!(pair[0].after, pair[1].before);
+            assert_eq!(pair[0].total + pair[1].count, pair[1].total);
+        }
+    }
+
+    #[test]
+    fn test_large_distances() {
+        let data = parse_input("R1000000000\nL4000000050\n").unwrap();
+        assert_eq!(part1(&data), 1);
+        assert_eq!(part2(&data), 50_000_001);
+
+        let data = parse_input("R18446744073709551615\n").unwrap();
+        assert_eq!(data[0].distance(), u64::MAX);
+        let mut dial = Dial::default().counting(Count::PassThrough);
+        assert_eq!(dial.rotate(&data[0]), 184467440737095516);
+        assert_eq!(dial.position(), 65);
+        let instruction = Instruction::new(Direction::Left, u64::MAX);
+        assert_eq!(dial.rotate(&instruction), 184467440737095516);
+        assert_eq!(dial.position(), 50);
+
+        let error = parse_input("R18446744073709551616\n").unwrap_err();
+        assert_eq!(error.text, "18446744073709551616");
+    }
+
+    #[test]
+    fn test_large_dial() {
+        let data = parse_input("R5\nL3\nL3\n").unwrap();
+        let dial = Dial::new(u64::MAX, u64::MAX - 2).with_targets([1, u64::MAX - 1]);
+        let steps: Vec<Step> = dial.counting(Count::PassThrough).trace(&data).collect();
+        assert_eq!(
+            steps.iter().map(|step| step.after).collect::<Vec<_>>(),
+            vec![3, 0, u64::MAX - 3]
+        );
+        assert_eq!(steps.last().unwrap().total, 4);
+    }
+
+    #[test]
+    fn test_starts_counting() {
+        let input = include_str!("../../examples/01.txt");
+        let data = parse_input(input).unwrap();
+        assert!(Dial::default().starts_counting(&data, 3).contains(&START));
+        let dial = Dial::default().counting(Count::PassThrough);
+        assert!(dial.starts_counting(&data, 6).contains(&START));
+        assert!(dial.starts_counting(&data, 100).is_empty());
+
+        let data = parse_input("R5\n").unwrap();
+        assert_eq!(Dial::new(10, 0).starts_counting(&data, 1), vec![5]);
+        assert_eq!(
+            Dial::new(10, 0)
+                .counting(Count::PassThrough)
+                .starts_counting(&data, 1),
+            vec![5, 6, 7, 8, 9]
+        );
+    }
+
+    #[test]
+    fn test_counts_by_start_against_dial() {
+        for seed in 0..500 {
+            let rng = &mut Rng::new(seed);
+            let size = rng.range(1..=50);
+            let targets: Vec<u64> = (0..rng.range(0..=3))
+                .map(|_| rng.range(0..=size - 1))
+                .collect();
+            let mode = if rng.chance(0.5) {
+                Count::LandOn
+            } else {
+                Count::PassThrough
+            };
+            let data = parse_input(&generate::day01(rng, 20)).unwrap();
+
+            let dial = Dial::new(size, 0)
+                .with_targets(targets.iter().copied())
+                .counting(mode);
+            let expected: Vec<u64> = (0..size)
+                .map(|start| {
+                    Dial::new(size, start)
+                        .with_targets(targets.iter().copied())
+                        .counting(mode)
+                        .count(&data)
+                })
+                .collect();
+            assert_eq!(dial.counts_by_start(&data), expected, "Seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn test_lock() {
+        let data = parse_keyed("A:L68\nB:R30\nA:L82\nB:L5\nC:R1\n")
+            .unwrap()
+            .instructions;
+        assert_eq!(data[1].dial, "B");
+        assert_eq!(data[1].instruction.to_string(), "R30");
+
+        let lock = Lock::new()
+            .with_dial("B", Dial::new(10, 5))
+            .with_dial("D", Dial::new(7, 0));
+        let stats = lock.stats(&data);
+        let names: Vec<&str> = stats.keys().map(String::as_str).collect();
+        assert_eq!(names, vec!["A", "B", "C", "D"]);
+        assert_eq!(
+            stats["A"],
+            Stats {
+                landings: 1,
+                crossings: 2
+            }
+        );
+        assert_eq!(
+            stats["B"],
+            Stats {
+                landings: 1,
+                crossings: 4
+            }
+        );
+        assert_eq!(stats["C"], Stats::default());
+        assert_eq!(stats["D"], Stats::default());
+        assert_eq!(
+            stats.values().copied().sum::<Stats>(),
+            Stats {
+                landings: 2,
+                crossings: 6
+            }
+        );
+    }
+
+    #[test]
+    fn test_declared_dials() {
+        let input = "B=10@5\nD=7@0\nA:L68\nB:R30\nA:L82\nB:L5\nC:R1\n";
+        let parsed = parse_keyed(input).unwrap();
+        assert_eq!(parsed.instructions.len(), 5);
+        let lock = Lock::new()
+            .with_dial("B", Dial::new(10, 5))
+            .with_dial("D", Dial::new(7, 0));
+        assert_eq!(
+            parsed.lock.stats(&parsed.instructions),
+            lock.stats(&parsed.instructions)
+        );
+
+        // 7 positions from 3, landing on the targets 6 then 1
+        let parsed = parse_keyed("A=7@3#1,6\nA:R3\nA:L5\nA:R1\n").unwrap();
+        assert_eq!(
+            parsed.lock.stats(&parsed.instructions)["A"],
+            Stats {
+                landings: 2,
+                crossings: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_keyed_errors() {
+        let error = parse_keyed("A=100\n").unwrap_err();
+        assert_eq!(error.message, "Invalid dial '100', expected SIZE@START");
+
+        let error = parse_keyed("A=0@0\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+        assert_eq!(error.message, "The dial needs at least one position");
+
+        let error = parse_keyed("A=10@10\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 6));
+        assert_eq!(error.message, "Position 10 is not on a dial of size 10");
+
+        let error = parse_keyed("A=10@5#0,12\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 10));
+        assert_eq!(error.message, "Position 12 is not on a dial of size 10");
+
+        let error = parse_keyed("A=10@5\nA=20@5\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.message, "Dial 'A' is already declared");
+
+        let error = parse_keyed("A:L5\nA=20@5\n").unwrap_err();
+        assert_eq!(error.message, "Dial 'A' is declared after its instructions");
+
+        let error = parse_keyed("A-1=10@5\n").unwrap_err();
+        assert_eq!(error.message, "Invalid dial name 'A-1'");
+
+        let error = parse_keyed("A:L68\nL30\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.message, "Missing dial name");
+
+        let error = parse_keyed("A:L68\nB-2:L30\n").unwrap_err();
+        assert_eq!(error.message, "Invalid dial name 'B-2'");
+
+        let error = parse_keyed("A:L68\nB:X30\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.message, "Invalid direction 'X'");
+    }
+
+    #[test]
+    fn test_stream() {
+        let input = include_str!("../../examples/01.txt");
+        let progress: Vec<Progress> = Stream::new(input.as_bytes())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(progress.len(), 10);
+        assert_eq!(
+            progress[2],
+            Progress {
+                line: 3,
+                part1: 1,
+                part2: 2
+            }
+        );
+        assert_eq!(
+            progress.last().unwrap(),
+            &Progress {
+                line: 10,
+                part1: 3,
+                part2: 6
+            }
+        );
+
+        let progress = Stream::new("R50\r\nL5".as_bytes()).last().unwrap().unwrap();
+        assert_eq!((progress.part1, progress.part2), (1, 1));
+    }
+
+    #[test]
+    fn test_stream_against_batch() {
+        for seed in 0..100 {
+            let input = generate::day01(&mut Rng::new(seed), 100);
+            let data = parse_input(&input).unwrap();
+            let progress = Stream::new(input.as_bytes()).last().unwrap().unwrap();
+            assert_eq!(
+                (progress.part1, progress.part2),
+                (part1(&data), part2(&data)),
+                "Seed {}",
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn test_stream_errors() {
+        let mut stream = Stream::new("L68\nL30\nR4x\nL5\n".as_bytes());
+        assert!(stream.next().unwrap().is_ok());
+        assert!(stream.next().unwrap().is_ok());
+        let Some(Err(StreamError::Parse(error))) = stream.next() else {
+            panic!("Expected a parse error");
+        };
+        assert_eq!((error.line, error.column), (3, 2));
+        assert_eq!(error.source_line, "R4x");
+        assert!(stream.next().is_none());
+    }
+
+    #[test]
+    fn test_part2_ending_on_zero() {
+        let data = parse_input("R50\nL100\n").unwrap();
+        assert_eq!(part2(&data), 2);
+    }
+
+    /// Turns the dial one click at a time. Returns the number of rotations
+    /// ending on a target, and the number of clicks landing on a target.
+    fn reference(size: u64, start: u64, targets: &[u64], data: &[Instruction]) -> (u64, u64) {
+        let (mut dial, mut rotations, mut clicks) = (start, 0, 0);
+        for instruction in data {
+            let step = match instruction.direction {
+                Direction::Left => size - 1,
+                Direction::Right => 1,
+            };
+            for _ in 0..instruction.distance {
+                dial = (dial + step) % size;
+                if targets.contains(&dial) {
+                    clicks += 1;
+                }
+            }
+            if targets.contains(&dial) {
+                rotations += 1;
+            }
+        }
+        (rotations, clicks)
+    }
+
+    #[test]
+    fn test_against_reference() {
+        for seed in 0..1000 {
+            let input = generate::day01(&mut Rng::new(seed), 20);
+            let data = parse_input(&input).unwrap();
+            assert_eq!(
+                (part1(&data), part2(&data)),
+                reference(SIZE, START, &[0], &data),
+                "Seed {}",
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn test_dial_against_reference() {
+        for seed in 0..1000 {
+            let rng = &mut Rng::new(seed);
+            let size = rng.range(1..=50);
+            let start = rng.range(0..=size - 1);
+            let targets: Vec<u64> = (0..rng.range(0..=3))
+                .map(|_| rng.range(0..=size - 1))
+                .collect();
+            let data = parse_input(&generate::day01(rng, 20)).unwrap();
+
+            let dial = Dial::new(size, start).with_targets(targets.iter().copied());
+            let (rotations, clicks) = reference(size, start, &targets, &data);
+            assert_eq!(dial.count(&data), rotations, "Seed {}", seed);
+            assert_eq!(
+                dial.counting(Count::PassThrough).count(&data),
+                clicks,
+                "Seed {}",
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn test_dial() {
+        let data = parse_input("R3\nL5\nR12\n").unwrap();
+        let dial = Dial::new(10, 0).with_targets([3, 8]);
+        assert_eq!(dial.count(&data), 2);
+        assert_eq!(dial.clone().counting(Count::PassThrough).count(&data), 4);
+
+        let mut dial = Dial::new(10, 0);
+        assert_eq!(dial.rotate(&data[1]), 0);
+        assert_eq!(dial.position(), 5);
+    }
+
+    fn l(dial: i16, dist: i16) -> i16 {
+        ((100 - dial).rem_euclid(100) + dist) / 100
+    }
+
+    fn r(dial: i16, dist: i16) -> i16 {
+        (dial + dist) / 100
+    }
+
+    #[test]
+    fn my_test() {
+        assert_eq!(l(0, 1), 0);
+        assert_eq!(l(0, 99), 0);
+        assert_eq!(l(0, 100), 1);
+
+        assert_eq!(l(10, 9), 0);
+        assert_eq!(l(10, 10), 1);
+        assert_eq!(l(10, 11), 1);
+
+        assert_eq!(l(10, 109), 1);
+        assert_eq!(l(10, 110), 2);
+        assert_eq!(l(10, 111), 2);
+
+        assert_eq!(r(10, 89), 0);
+        assert_eq!(r(10, 90), 1);
+        assert_eq!(r(10, 91), 1);
+
+        assert_eq!(r(10, 189), 1);
+        assert_eq!(r(10, 190), 2);
+        assert_eq!(r(10, 191), 2);
+
+        assert_eq!(r(0, 1), 0);
+        assert_eq!(r(0, 99), 0);
+        assert_eq!(r(0, 100), 1);
+    }
+}
@@ -0,0 +1,887 @@
+use std::ops::RangeInclusive;
+
+use itertools::Itertools;
+use serde::Serialize;
+
+use crate::{
+    Solution,
+    parse::{ParseError, parse_number},
+};
+
+pub struct Day02;
+
+/// What to do with ranges sharing IDs, which would count them several times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overlaps {
+    /// Fail to parse the input.
+    Reject,
+    /// Merge the ranges, so every ID counts once. The merged ranges are sorted.
+    Merge,
+}
+
+fn parse_input(input: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+    parse_with(input, Overlaps::Reject, |number| {
+        parse_number(Day02::DAY, input, number)
+    })
+}
+
+/// Parses the input like the puzzle, but merges the overlapping ranges.
+pub fn parse_merged(input: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+    parse_with(input, Overlaps::Merge, |number| {
+        parse_number(Day02::DAY, input, number)
+    })
+}
+
+/// Parses ranges of IDs written in `base`, like `ff-1a0` in base 16.
+pub fn parse_ranges(
+    input: &str,
+    base: Base,
+    overlaps: Overlaps,
+) -> Result<Vec<(u128, u128)>, ParseError> {
+    parse_with(input, overlaps, |number| {
+        u128::from_str_radix(number, base.0).map_err(|_| {
+            ParseError::at(
+                Day02::DAY,
+                input,
+                number,
+                format!("Invalid number '{}' in base {}", number, base.0),
+            )
+        })
+    })
+}
+
+/// Parses the ranges separated by commas, parsing their ends with `parse`.
+/// Reversed ranges are errors, and overlapping ones are handled as requested.
+fn parse_with<T, F>(input: &str, overlaps: Overlaps, parse: F) -> Result<Vec<(T, T)>, ParseError>
+where
+    T: Ord + Copy,
+    F: Fn(&str) -> Result<T, ParseError>,
+{
+    let ranges: Vec<(&str, T, T)> = input
+        .trim_end()
+        .split(',')
+        .map(|range| {
+            let Some((start, end)) = range.split_once('-') else {
+                return Err(ParseError::at(
+                    Day02::DAY,
+                    input,
+                    range,
+                    format!("Invalid range '{}'", range),
+                ));
+            };
+            let (start, end) = (parse(start)?, parse(end)?);
+            if end < start {
+                return Err(ParseError::at(
+                    Day02::DAY,
+                    input,
+                    range,
+                    format!("Reversed range '{}'", range),
+                ));
+            }
+            Ok((range, start, end))
+        })
+        .collect::<Result<_, _>>()?;
+
+    let mut sorted: Vec<&(&str, T, T)> = ranges.iter().collect();
+    sorted.sort_by_key(|&&(_, start, end)| (start, end));
+    let mut merged: Vec<(T, T)> = Vec::new();
+    // The range reaching the furthest so far
+    let mut furthest: Option<&(&str, T, T)> = None;
+    for range in sorted {
+        let &(text, start, end) = range;
+        match furthest {
+            Some(&(previous, _, previous_end)) if start <= previous_end => {
+                if overlaps == Overlaps::Reject {
+                    return Err(ParseError::at(
+                        Day02::DAY,
+                        input,
+                        text,
+                        format!("Range '{}' overlaps '{}'", text, previous),
+                    ));
+                }
+                let last = merged.last_mut().expect("Merged the furthest range");
+                last.1 = last.1.max(end);
+            }
+            _ => merged.push((start, end)),
+        }
+        if furthest.is_none_or(|&(_, _, furthest_end)| furthest_end < end) {
+            furthest = Some(range);
+        }
+    }
+
+    Ok(match overlaps {
+        Overlaps::Reject => ranges
+            .into_iter()
+            .map(|(_, start, end)| (start, end))
+            .collect(),
+        Overlaps::Merge => merged,
+    })
+}
+
+/// Sum of the IDs from `begin_range` to `end_range` made of a block repeated
+/// twice.
+fn part1_single_range(begin_range: u64, end_range: u64) -> u128 {
+    doubled_sum(Base::DECIMAL, begin_range as u128, end_range as u128)
+        .expect("The sums over u64 ranges fit in a u128")
+}
+
+fn part1(data: &[(u64, u64)]) -> u128 {
+    data.iter()
+        .map(|(start, end)| part1_single_range(*start, *end))
+        .sum()
+}
+
+/// A numeric base from 2 to 36, in which the IDs are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Base(u32);
+
+impl Base {
+    pub const DECIMAL: Base = Base(10);
+
+    pub fn new(radix: u32) -> Option<Base> {
+        (2..=36).contains(&radix).then_some(Base(radix))
+    }
+
+    pub fn radix(self) -> u32 {
+        self.0
+    }
+
+    /// `radix^exponent`, or `None` if it doesn't fit in a `u128`.
+    fn pow(self, exponent: u32) -> Option<u128> {
+        (self.0 as u128).checked_pow(exponent)
+    }
+
+    fn digits(self, x: u128) -> u32 {
+        x.checked_ilog(self.0 as u128).map_or(1, |log| log + 1)
+    }
+
+    /// The number repeating a block of `block` digits to get `length` digits:
+    /// 1, 101, 1001001... in base 10. `None` if it doesn't fit in a `u128`.
+    fn repunit(self, length: u32, block: u32) -> Option<u128> {
+        let shift = self.pow(block)?;
+        (1..length / block).try_fold(1u128, |repunit, _| {
+            repunit.checked_mul(shift)?.checked_add(1)
+        })
+    }
+}
+
+/// An invalid ID, made of a block of digits repeated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct InvalidId {
+    pub id: u128,
+    pub block: u128,
+    pub repetitions: u32,
+}
+
+/// Splits the range of IDs by number of digits. Yields the number of digits
+/// with the first and last IDs having it.
+fn bands(
+    base: Base,
+    begin_range: u128,
+    end_range: u128,
+) -> impl Iterator<Item = (u32, u128, u128)> {
+    (base.digits(begin_range)..=base.digits(end_range)).map(move |length| {
+        let smallest = base
+            .pow(length - 1)
+            .expect("Not above the end of the range");
+        let first = begin_range.max(smallest);
+        let last = base
+            .pow(length)
+            .map_or(end_range, |power| end_range.min(power - 1));
+        (length, first, last)
+    })
+}
+
+/// The blocks of `block` digits that, repeated, give numbers of `length`
+/// digits from `first` to `last`. Returns the blocks with the repunit
+/// repeating them, or `None` if the repeated blocks can't fit in a `u128`.
+fn blocks(
+    base: Base,
+    first: u128,
+    last: u128,
+    length: u32,
+    block: u32,
+) -> Option<(RangeInclusive<u128>, u128)> {
+    let repunit = base.repunit(length, block)?;
+    let power = base.pow(block).expect("Shorter than the IDs");
+    let low = first.div_ceil(repunit).max(power / base.0 as u128);
+    let high = (last / repunit).min(power - 1);
+    Some((low..=high, repunit))
+}
+
+/// Sum of the numbers from `first` to `last`, which all have `length` digits,
+/// made of a block of `block` digits repeated. `None` if the sum overflows.
+fn sum_repeated(base: Base, first: u128, last: u128, length: u32, block: u32) -> Option<u128> {
+    let Some((blocks, repunit)) = blocks(base, first, last, length, block) else {
+        return Some(0);
+    };
+    if blocks.is_empty() {
+        return Some(0);
+    }
+    let (low, high) = blocks.into_inner();
+    // Halve the even one of the count and the sum of the ends
+    let (count, ends) = (high - low + 1, low.checked_add(high)?);
+    let sum = if count.is_multiple_of(2) {
+        (count / 2).checked_mul(ends)?
+    } else {
+        count.checked_mul(ends / 2)?
+    };
+    sum.checked_mul(repunit)
+}
+
+/// Yields the IDs made of a block of `block` digits repeated, in increasing
+/// order.
+fn repeated_blocks(
+    base: Base,
+    first: u128,
+    last: u128,
+    length: u32,
+    block: u32,
+) -> impl Iterator<Item = InvalidId> {
+    blocks(base, first, last, length, block)
+        .into_iter()
+        .flat_map(move |(blocks, repunit)| {
+            blocks.map(move |block_id| InvalidId {
+                id: block_id * repunit,
+                block: block_id,
+                repetitions: length / block,
+            })
+        })
+}
+
+/// Distinct prime factors of `n`.
+fn prime_factors(mut n: u32) -> Vec<u32> {
+    let mut factors = Vec::new();
+    let mut factor = 2;
+    while factor * factor <= n {
+        if n.is_multiple_of(factor) {
+            factors.push(factor);
+            while n.is_multiple_of(factor) {
+                n /= factor;
+            }
+        }
+        factor += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+/// Checks if a block of `length` digits is itself a smaller block repeated.
+fn is_repeated_block(base: Base, block: u128, length: u32) -> bool {
+    prime_factors(length).into_iter().any(|prime| {
+        let repunit = base
+            .repunit(length, length / prime)
+            .expect("Not above the block");
+        block.is_multiple_of(repunit)
+    })
+}
+
+/// Yields the invalid IDs of part 1 in the range, in increasing order: the IDs
+/// made of a block repeated twice.
+pub fn doubled_ids(
+    base: Base,
+    begin_range: u128,
+    end_range: u128,
+) -> impl Iterator<Item = InvalidId> {
+    bands(base, begin_range, end_range)
+        .filter(|(length, _, _)| length.is_multiple_of(2))
+        .flat_map(move |(length, first, last)| {
+            repeated_blocks(base, first, last, length, length / 2)
+        })
+}
+
+/// Yields the invalid IDs of part 2 in the range, in increasing order: the IDs
+/// made of a block repeated at least twice. IDs are reported with their
+/// shortest block, so `1111` is `1` repeated 4 times.
+pub fn repeated_ids(
+    base: Base,
+    begin_range: u128,
+    end_range: u128,
+) -> impl Iterator<Item = InvalidId> {
+    bands(base, begin_range, end_range).flat_map(move |(length, first, last)| {
+        (1..length)
+            .filter(move |block| length.is_multiple_of(*block))
+            .map(move |block| {
+                repeated_blocks(base, first, last, length, block)
+                    .filter(move |id| !is_repeated_block(base, id.block, block))
+            })
+            .kmerge_by(|a, b| a.id < b.id)
+    })
+}
+
+/// Sum of the IDs of [`doubled_ids`], or `None` if it overflows.
+pub fn doubled_sum(base: Base, begin_range: u128, end_range: u128) -> Option<u128> {
+    bands(base, begin_range, end_range)
+        .filter(|(length, _, _)| length.is_multiple_of(2))
+        .try_fold(0u128, |sum, (length, first, last)| {
+            sum.checked_add(sum_repeated(base, first, last, length, length / 2)?)
+        })
+}
+
+/// Sum of the IDs of [`repeated_ids`], or `None` if it overflows.
+pub fn repeated_sum(base: Base, begin_range: u128, end_range: u128) -> Option<u128> {
+    let (mut added, mut removed) = (0u128, 0u128);
+    for (length, first, last) in bands(base, begin_range, end_range) {
+        // A number repeats a block if it repeats a block of length / p digits
+        // p times for a prime p. Count the numbers repeating blocks for several
+        // primes once with inclusion–exclusion: they repeat the block of
+        // length / (product of the primes) digits.
+        let primes = prime_factors(length);
+        for subset in 1..1u32 << primes.len() {
+            let product: u32 = primes
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| subset & (1 << index) != 0)
+                .map(|(_, prime)| prime)
+                .product();
+            let term = sum_repeated(base, first, last, length, length / product)?;
+            if subset.count_ones() % 2 == 1 {
+                added = added.checked_add(term)?;
+            } else {
+                removed = removed.checked_add(term)?;
+            }
+        }
+    }
+    Some(added - removed)
+}
+
+fn part2_range_sum_invalid(begin_range: u64, end_range: u64) -> u128 {
+    repeated_sum(Base::DECIMAL, begin_range as u128, end_range as u128)
+        .expect("The sums over u64 ranges fit in a u128")
+}
+
+/// Sum of the IDs made of a block repeated a number of times accepted by
+/// `accepts`, or `None` if it overflows. Blocks are repeated at least twice,
+/// and an ID may be split into several numbers of blocks: `111111` is `1`
+/// repeated 6 times, `11` 3 times and `111` twice.
+///
+/// Part 1 accepts 2 repetitions only and part 2 any number of repetitions, but
+/// this also answers queries like exactly 3, at most 4 or a prime number of
+/// repetitions.
+pub fn repetitions_sum<F>(
+    base: Base,
+    begin_range: u128,
+    end_range: u128,
+    accepts: F,
+) -> Option<u128>
+where
+    F: Fn(u32) -> bool,
+{
+    let (mut added, mut removed) = (0u128, 0u128);
+    for (length, first, last) in bands(base, begin_range, end_range) {
+        // An ID whose shortest block is repeated m times is also split into r
+        // blocks for every divisor r of m.
+        let repetitions = (2..=length).filter(|count| length.is_multiple_of(*count));
+        for count in repetitions {
+            if !(2..=count).any(|r| count.is_multiple_of(r) && accepts(r)) {
+                continue;
+            }
+            // The IDs repeating a block of `block` digits, minus the ones
+            // repeating a shorter block, with the Möbius inversion formula.
+            let block = length / count;
+            for divisor in (1..=block).filter(|divisor| block.is_multiple_of(*divisor)) {
+                let term = || sum_repeated(base, first, last, length, block / divisor);
+                match mobius(divisor) {
+                    1 => added = added.checked_add(term()?)?,
+                    -1 => removed = removed.checked_add(term()?)?,
+                    _ => {}
+                }
+            }
+        }
+    }
+    Some(added - removed)
+}
+
+/// The Möbius function: 0 if `n` has a square factor, else 1 or -1 for an even
+/// or odd number of prime factors.
+fn mobius(n: u32) -> i32 {
+    let primes = prime_factors(n);
+    if primes.iter().product::<u32>() != n {
+        0
+    } else if primes.len().is_multiple_of(2) {
+        1
+    } else {
+        -1
+    }
+}
+
+fn part2(data: &[(u64, u64)]) -> u128 {
+    data.iter()
+        .map(|(start, end)| part2_range_sum_invalid(*start, *end))
+        .sum()
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Data = Vec<(u64, u64)>;
+    // Sums over wide ranges don't fit in a u64
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Answer1 {
+        part1(data)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Answer2 {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::{self, Rng};
+
+    fn double(x: u64) -> u64 {
+        let num_digits = x.ilog10() + 1;
+        let mul = 10u64.pow(num_digits);
+        x * mul + x
+    }
+
+    #[test]
+    fn test_doubled_blocks() {
+        for (block, id) in [(12, 1212), (123, 123123), (1, 11)] {
+            assert_eq!(
+                doubled_ids(Base::DECIMAL, id, id).collect::<Vec<_>>(),
+                vec![InvalidId {
+                    id,
+                    block,
+                    repetitions: 2
+                }]
+            );
+            assert_eq!(part1_single_range(id as u64, id as u64), id);
+        }
+        assert_eq!(Base::DECIMAL.repunit(6, 3), Some(1001));
+        assert_eq!(Base::DECIMAL.repunit(6, 2), Some(10101));
+    }
+
+    #[test]
+    fn test_example_part1() {
+        let input = include_str!("../../examples/02.txt");
+        let data = parse_input(input).unwrap();
+        assert_eq!(part1(&data), 1227775554);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let input = include_str!("../../examples/02.txt");
+        let data = parse_input(input).unwrap();
+        assert_eq!(part2(&data), 4174379265);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("11-22,95-9x").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 10));
+        assert_eq!(error.message, "Invalid number '9x'");
+
+        let error = parse_input("11-22,115-95").unwrap_err();
+        assert_eq!(error.message, "Reversed range '115-95'");
+        assert_eq!(error.column, 7);
+
+        let error = parse_input("95-115,11-22,20-30").unwrap_err();
+        assert_eq!(error.message, "Range '20-30' overlaps '11-22'");
+        assert_eq!(error.column, 14);
+        let error = parse_input("11-99,20-30,50-60").unwrap_err();
+        assert_eq!(error.message, "Range '20-30' overlaps '11-99'");
+        let error = parse_input("11-99,50-60,20-30").unwrap_err();
+        assert_eq!(error.message, "Range '20-30' overlaps '11-99'");
+        let error = parse_input("11-22,22-33").unwrap_err();
+        assert_eq!(error.message, "Range '22-33' overlaps '11-22'");
+        assert!(parse_input("11-22,23-33").is_ok());
+    }
+
+    #[test]
+    fn test_merge_overlaps() {
+        assert_eq!(
+            parse_merged("95-115,11-22,20-30,23-33,1-5,3-4\n").unwrap(),
+            vec![(1, 5), (11, 33), (95, 115)]
+        );
+        assert_eq!(parse_merged("11-99,20-30,50-60").unwrap(), vec![(11, 99)]);
+        assert!(parse_merged("11-22,22-11").is_err());
+
+        // Every invalid ID counts once
+        let data = parse_merged("11-22,15-33,22-22").unwrap();
+        assert_eq!(part1(&data), 11 + 22 + 33);
+        assert_eq!(part2(&data), 11 + 22 + 33);
+    }
+
+    #[test]
+    fn test_part1_single_range() {
+        // Single digit starts
+        assert_eq!(part1_single_range(1, 9), 0);
+        assert_eq!(part1_single_range(7, 7), 0);
+        assert_eq!(part1_single_range(5, 22), 11 + 22);
+
+        // Ranges crossing digit count boundaries
+        assert_eq!(part1_single_range(95, 1015), 99 + 1010);
+        assert_eq!(part1_single_range(999, 1011), 1010);
+        assert_eq!(part1_single_range(5, 123456), 3174576);
+
+        // Starts with an odd number of digits
+        assert_eq!(part1_single_range(123, 2000), 14645);
+        assert_eq!(part1_single_range(100, 999), 0);
+        assert_eq!(part1_single_range(12345, 99999), 0);
+
+        assert_eq!(part1_single_range(11, 11), 11);
+        assert_eq!(part1_single_range(1000, 1009), 0);
+    }
+
+    #[test]
+    fn test_part1_wide_ranges() {
+        // Every doubled ID of up to 10 digits, from the blocks
+        let reference = |start, end| -> u128 {
+            (1..100_000)
+                .map(double)
+                .filter(|id| (start..=end).contains(id))
+                .map(u128::from)
+                .sum()
+        };
+        for (start, end) in [
+            (5, 123456),
+            (1, 9_999_999_999),
+            (10, 99),
+            (123, 98_765_432),
+            (9_999, 1_000_000),
+            (1_000, 1_234_567_890),
+            (56_565, 5_656_565_656),
+        ] {
+            assert_eq!(part1_single_range(start, end), reference(start, end));
+        }
+
+        assert_eq!(part1_single_range(0, 99), 495);
+        assert_eq!(part1_single_range(22, 11), 0);
+        assert_eq!(
+            part1_single_range(18446744071844674406, u64::MAX),
+            18446744071844674407
+        );
+    }
+
+    #[test]
+    fn test_part2_range_sum_invalid() {
+        assert_eq!(part2_range_sum_invalid(11, 22), 33);
+        assert_eq!(part2_range_sum_invalid(95, 115), 99 + 111);
+
+        assert_eq!(part2_range_sum_invalid(998, 1012), 999 + 1010);
+        assert_eq!(part2_range_sum_invalid(1188511880, 1188511890), 1188511885);
+        assert_eq!(part2_range_sum_invalid(222220, 222224), 222222);
+        assert_eq!(part2_range_sum_invalid(1698522, 1698528), 0);
+        assert_eq!(part2_range_sum_invalid(446443, 446449), 446446);
+        assert_eq!(part2_range_sum_invalid(38593856, 38593862), 38593859);
+        assert_eq!(part2_range_sum_invalid(565653, 565659), 565656);
+        assert_eq!(part2_range_sum_invalid(824824821, 824824827), 824824824);
+        assert_eq!(part2_range_sum_invalid(2121212118, 2121212124), 2121212121);
+    }
+
+    /// Sums the IDs of the ranges one by one, keeping the invalid ones.
+    fn sum_invalid(data: &[(u64, u64)], is_invalid: fn(&str) -> bool) -> u128 {
+        data.iter()
+            .flat_map(|&(start, end)| start..=end)
+            .filter(|id| is_invalid(&id.to_string()))
+            .map(u128::from)
+            .sum()
+    }
+
+    /// An ID made of the same sequence of digits repeated twice.
+    fn is_twice(id: &str) -> bool {
+        let (left, right) = id.split_at(id.len() / 2);
+        left == right
+    }
+
+    /// An ID made of the same sequence of digits repeated at least twice.
+    fn is_repeated(id: &str) -> bool {
+        (1..id.len())
+            .any(|len| id.len().is_multiple_of(len) && id[..len].repeat(id.len() / len) == id)
+    }
+
+    #[test]
+    fn test_against_reference() {
+        for seed in 0..200 {
+            let input = generate::day02(&mut Rng::new(seed), 2);
+            let data = parse_input(&input).unwrap();
+            assert_eq!(part1(&data), sum_invalid(&data, is_twice), "Seed {}", seed);
+            assert_eq!(
+                part2(&data),
+                sum_invalid(&data, is_repeated),
+                "Seed {}",
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_ids() {
+        let id = |id, block, repetitions| InvalidId {
+            id,
+            block,
+            repetitions,
+        };
+        assert_eq!(
+            doubled_ids(Base::DECIMAL, 95, 1111).collect::<Vec<_>>(),
+            vec![id(99, 9, 2), id(1010, 10, 2), id(1111, 11, 2)]
+        );
+        assert_eq!(
+            repeated_ids(Base::DECIMAL, 95, 1111).collect::<Vec<_>>(),
+            [id(99, 9, 2)]
+                .into_iter()
+                .chain((1..=9).map(|block| id(111 * block, block, 3)))
+                .chain([id(1010, 10, 2), id(1111, 1, 4)])
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            repeated_ids(Base::DECIMAL, 121212, 123123).collect::<Vec<_>>(),
+            vec![id(121212, 12, 3), id(122122, 122, 2), id(123123, 123, 2)]
+        );
+        assert_eq!(repeated_ids(Base::DECIMAL, 22, 11).count(), 0);
+    }
+
+    #[test]
+    fn test_invalid_ids_against_sums() {
+        for seed in 0..200 {
+            let input = generate::day02(&mut Rng::new(seed), 5);
+            for (start, end) in parse_input(&input).unwrap() {
+                let (start, end) = (start as u128, end as u128);
+                let ids: Vec<u128> = repeated_ids(Base::DECIMAL, start, end)
+                    .map(|id| id.id)
+                    .collect();
+                assert!(ids.is_sorted_by(|a, b| a < b), "Seed {}", seed);
+                assert!(
+                    ids.iter().all(|id| is_repeated(&id.to_string())),
+                    "Seed {}",
+                    seed
+                );
+                assert_eq!(
+                    Some(ids.iter().sum::<u128>()),
+                    repeated_sum(Base::DECIMAL, start, end)
+                );
+                let ids = doubled_ids(Base::DECIMAL, start, end).map(|id| id.id);
+                assert_eq!(Some(ids.sum()), doubled_sum(Base::DECIMAL, start, end));
+            }
+        }
+    }
+
+    /// Writes `x` in base `radix`, with lowercase letters after 9.
+    fn to_base(mut x: u128, radix: u32) -> String {
+        let mut digits = Vec::new();
+        loop {
+            digits.push(char::from_digit((x % radix as u128) as u32, radix).unwrap());
+            x /= radix as u128;
+            if x == 0 {
+                break;
+            }
+        }
+        digits.iter().rev().collect()
+    }
+
+    #[test]
+    fn test_bases() {
+        assert_eq!(Base::new(1), None);
+        assert_eq!(Base::new(37), None);
+        let hex = Base::new(16).unwrap();
+        assert_eq!(
+            doubled_ids(hex, 0x10, 0x101)
+                .map(|id| id.id)
+                .collect::<Vec<_>>(),
+            vec![
+                0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee,
+                0xff
+            ]
+        );
+        assert_eq!(
+            repeated_ids(Base::new(2).unwrap(), 0b1000, 0b1111).collect::<Vec<_>>(),
+            vec![
+                InvalidId {
+                    id: 0b1010,
+                    block: 0b10,
+                    repetitions: 2
+                },
+                InvalidId {
+                    id: 0b1111,
+                    block: 0b1,
+                    repetitions: 4
+                },
+            ]
+        );
+
+        let rng = &mut Rng::new(0);
+        for _ in 0..500 {
+            let radix = rng.range(2..=36) as u32;
+            let base = Base::new(radix).unwrap();
+            let start = rng.range(1..=(radix as u64).pow(5)) as u128;
+            let end = start + rng.range(0..=500) as u128;
+            let reference = |is_invalid: fn(&str) -> bool| {
+                (start..=end)
+                    .filter(|&id| is_invalid(&to_base(id, radix)))
+                    .collect::<Vec<_>>()
+            };
+            let ids = |ids: Vec<InvalidId>| ids.iter().map(|id| id.id).collect::<Vec<_>>();
+
+            let doubled = reference(is_twice);
+            assert_eq!(ids(doubled_ids(base, start, end).collect()), doubled);
+            assert_eq!(doubled_sum(base, start, end), Some(doubled.iter().sum()));
+            let repeated = reference(is_repeated);
+            assert_eq!(ids(repeated_ids(base, start, end).collect()), repeated);
+            assert_eq!(repeated_sum(base, start, end), Some(repeated.iter().sum()));
+        }
+    }
+
+    #[test]
+    fn test_u128_ranges() {
+        assert_eq!(doubled_sum(Base::DECIMAL, 0, u128::MAX), None);
+        assert_eq!(repeated_sum(Base::DECIMAL, 0, u128::MAX), None);
+
+        let start = 340282366920928463463374607431768211455;
+        assert_eq!(
+            repeated_ids(Base::DECIMAL, start, u128::MAX).collect::<Vec<_>>(),
+            vec![InvalidId {
+                id: 340282366920934028236692093402823669209,
+                block: 3402823669209,
+                repetitions: 3,
+            }]
+        );
+        assert_eq!(
+            repeated_sum(Base::DECIMAL, start, u128::MAX),
+            Some(340282366920934028236692093402823669209)
+        );
+        assert_eq!(doubled_sum(Base::DECIMAL, start, u128::MAX), Some(0));
+
+        // 2^128 - 1 is 1 repeated 128 times in base 2
+        let binary = Base::new(2).unwrap();
+        assert_eq!(
+            repeated_sum(binary, u128::MAX - 1, u128::MAX),
+            Some(u128::MAX)
+        );
+        assert_eq!(
+            doubled_sum(binary, u128::MAX - 1, u128::MAX),
+            Some(u128::MAX)
+        );
+    }
+
+    #[test]
+    fn test_parse_ranges() {
+        let hex = Base::new(16).unwrap();
+        assert_eq!(
+            parse_ranges("ff-1a0,AA-bb\n", hex, Overlaps::Reject).unwrap(),
+            vec![(0xff, 0x1a0), (0xaa, 0xbb)]
+        );
+        let error = parse_ranges("ff-1g0", hex, Overlaps::Reject).unwrap_err();
+        assert_eq!(error.message, "Invalid number '1g0' in base 16");
+        assert_eq!(error.column, 4);
+    }
+
+    #[test]
+    fn test_repetitions_sum() {
+        let sum = |start, end, accepts: fn(u32) -> bool| {
+            repetitions_sum(Base::DECIMAL, start, end, accepts).unwrap()
+        };
+        assert_eq!(sum(95, 115, |r| r == 3), 111);
+        assert_eq!(sum(1000, 1111, |r| r <= 4), 1010 + 1111);
+        assert_eq!(sum(1000, 1111, |r| r == 4), 1111);
+        assert_eq!(sum(1000, 1111, |r| r == 3), 0);
+        // 6 digits: 123123 is 123 repeated twice, 121212 12 three times, and
+        // 111111 both, and also 1 six times
+        assert_eq!(sum(111110, 123123, |r| r == 6), 111111);
+        assert_eq!(sum(111110, 123123, |r| r == 3), 111111 + 121212);
+        assert_eq!(
+            sum(111110, 123123, |r| r == 2),
+            (111..=123).map(|block| block * 1001).sum()
+        );
+
+        for (start, end) in [(1, 1_000_000), (1212121218, 2121212124)] {
+            assert_eq!(
+                repetitions_sum(Base::DECIMAL, start, end, |r| r == 2),
+                doubled_sum(Base::DECIMAL, start, end)
+            );
+            assert_eq!(
+                repetitions_sum(Base::DECIMAL, start, end, |_| true),
+                repeated_sum(Base::DECIMAL, start, end)
+            );
+        }
+        assert_eq!(repetitions_sum(Base::DECIMAL, 0, u128::MAX, |_| true), None);
+    }
+
+    #[test]
+    fn test_repetitions_sum_against_reference() {
+        let queries: [fn(u32) -> bool; 4] = [
+            |r| r == 3,
+            |r| r <= 4,
+            |r| [2, 3, 5, 7].contains(&r),
+            |r| r % 2 == 1,
+        ];
+        for seed in 0..50 {
+            let input = generate::day02(&mut Rng::new(seed), 2);
+            for (start, end) in parse_input(&input).unwrap() {
+                for accepts in queries {
+                    let reference: u64 = (start..=end)
+                        .filter(|id| {
+                            let id = id.to_string();
+                            let length = id.len() as u32;
+                            (2..=length).any(|r| {
+                                length.is_multiple_of(r)
+                                    && accepts(r)
+                                    && id[..(length / r) as usize].repeat(r as usize) == id
+                            })
+                        })
+                        .sum();
+                    assert_eq!(
+                        repetitions_sum(Base::DECIMAL, start as u128, end as u128, accepts),
+                        Some(reference as u128),
+                        "Range {}-{}",
+                        start,
+                        end
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sums_above_u64() {
+        let doubled = part1_single_range(1, u64::MAX);
+        assert_eq!(doubled, 12509613850169742155792778978);
+        let repeated = part2_range_sum_invalid(1, u64::MAX);
+        assert!(repeated > doubled);
+        assert_eq!(
+            Some(repeated),
+            repetitions_sum(Base::DECIMAL, 1, u64::MAX as u128, |_| true)
+        );
+        let data = vec![(1, 99), (100, u64::MAX)];
+        assert_eq!(part1(&data), doubled);
+        assert_eq!(part2(&data), repeated);
+    }
+
+    #[test]
+    fn test_part2_large_ranges() {
+        assert_eq!(
+            part2_range_sum_invalid(1212121218, 2121212124),
+            15165454543938
+        );
+        assert_eq!(
+            part2_range_sum_invalid(18446744071844674406, u64::MAX),
+            18446744071844674407
+        );
+    }
+
+    #[test]
+    fn test_single_range_against_reference() {
+        let rng = &mut Rng::new(0);
+        for _ in 0..2000 {
+            let start = rng.range(1..=1_000_000);
+            let end = start + rng.range(0..=1_000);
+            assert_eq!(
+                part1_single_range(start, end),
+                sum_invalid(&[(start, end)], is_twice),
+                "Range {}-{}",
+                start,
+                end
+            );
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use serde::Serialize;
+
+use crate::{
+    Solution,
+    parse::{self, ParseError},
+};
+
+pub struct Day03;
+
+fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            if let Some((index, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+                return Err(ParseError::at(
+                    Day03::DAY,
+                    input,
+                    &line[index..index + c.len_utf8()],
+                    format!("Invalid battery '{}'", c),
+                ));
+            }
+            if line.len() < PART2_BATTERIES {
+                return Err(ParseError::at_offset(
+                    Day03::DAY,
+                    input,
+                    parse::offset_of(input, line),
+                    line.len(),
+                    format!(
+                        "Bank of {} batteries, at least {} are needed",
+                        line.len(),
+                        PART2_BATTERIES
+                    ),
+                ));
+            }
+            Ok(line.to_string())
+        })
+        .collect()
+}
+
+/// Batteries turned on in each bank for part 1 and part 2.
+pub const PART1_BATTERIES: usize = 2;
+pub const PART2_BATTERIES: usize = 12;
+
+/// The batteries turned on in a bank.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Selection {
+    /// Positions of the batteries in the bank, in increasing order.
+    pub indices: Vec<usize>,
+    pub joltage: u64,
+}
+
+/// The positions of the `count` batteries of the bank making the largest
+/// number, keeping their order.
+///
+/// Going through the bank, a battery replaces the smaller batteries picked
+/// before it as long as enough batteries remain, so the picked batteries form
+/// a stack of non-increasing digits and the bank is read once.
+///
+/// Panics if the bank has less than `count` batteries.
+pub fn select_batteries(bank: &str, count: usize) -> Vec<usize> {
+    assert!(count <= bank.len(), "Not enough batteries in the bank");
+    let digits = bank.as_bytes();
+    let mut drops = bank.len() - count;
+    let mut stack: Vec<usize> = Vec::with_capacity(bank.len());
+    for (index, digit) in digits.iter().enumerate() {
+        while drops > 0 && stack.last().is_some_and(|&top| digits[top] < *digit) {
+            stack.pop();
+            drops -= 1;
+        }
+        stack.push(index);
+    }
+    stack.truncate(count);
+    stack
+}
+
+/// The largest number made of `count` batteries of the bank, as a string of
+/// digits.
+pub fn largest_digits(bank: &str, count: usize) -> String {
+    select_batteries(bank, count)
+        .into_iter()
+        .map(|index| char::from(bank.as_bytes()[index]))
+        .collect()
+}
+
+/// The batteries making the largest joltage with `count` batteries of the
+/// bank. `count` must be at most 19 for the joltage to fit in a `u64`.
+pub fn largest_joltage(bank: &str, count: usize) -> Selection {
+    let indices = select_batteries(bank, count);
+    let joltage = indices.iter().fold(0, |joltage, &index| {
+        joltage * 10 + (bank.as_bytes()[index] - b'0') as u64
+    });
+    Selection { indices, joltage }
+}
+
+fn part1(data: &[String]) -> u64 {
+    data.iter()
+        .map(|x| largest_joltage(x, PART1_BATTERIES).joltage)
+        .sum()
+}
+
+fn part2(data: &[String]) -> u64 {
+    data.iter()
+        .map(|x| largest_joltage(x, PART2_BATTERIES).joltage)
+        .sum()
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Data = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Answer1 {
+        part1(data)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Answer2 {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::{self, Rng};
+
+    mod part1 {
+        use super::*;
+
+        #[test]
+        fn test_exemple_day3_part1() {
+            let input = include_str!("../../examples/03.txt");
+            let data = parse_input(input).unwrap();
+            assert_eq!(part1(&data), 357);
+        }
+
+        #[test]
+        fn test_exemple_day3_part2() {
+            let input = include_str!("../../examples/03.txt");
+            let data = parse_input(input).unwrap();
+            assert_eq!(part2(&data), 3121910778619);
+        }
+
+        #[test]
+        fn test_parse_error() {
+            let error = parse_input("987654321111111\n81111x111111119\n").unwrap_err();
+            assert_eq!((error.line, error.column), (2, 6));
+            assert_eq!(error.message, "Invalid battery 'x'");
+
+            let error = parse_input("987654321111111\n12\n3\n").unwrap_err();
+            assert_eq!((error.line, error.column), (2, 1));
+            assert_eq!(error.message, "Bank of 2 batteries, at least 12 are needed");
+            let error = parse_input("987654321111111\n\n").unwrap_err();
+            assert_eq!(error.line, 2);
+            assert_eq!(error.message, "Bank of 0 batteries, at least 12 are needed");
+            assert!(parse_input("123456789012\n").is_ok());
+        }
+
+        #[test]
+        fn test_largest_joltage() {
+            let joltage = |bank| largest_joltage(bank, 2).joltage;
+            assert_eq!(joltage("987654321111111"), 98);
+            assert_eq!(joltage("811111111111119"), 89);
+            assert_eq!(joltage("234234234234278"), 78);
+            assert_eq!(joltage("818181911112111"), 92);
+        }
+
+        #[test]
+        fn test_selection() {
+            assert_eq!(
+                largest_joltage("818181911112111", 2),
+                Selection {
+                    indices: vec![6, 11],
+                    joltage: 92
+                }
+            );
+            assert_eq!(
+                largest_joltage("234234234234278", 12),
+                Selection {
+                    indices: vec![2, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14],
+                    joltage: 434234234278
+                }
+            );
+            // The first of equal batteries is kept
+            assert_eq!(select_batteries("9959", 2), vec![0, 1]);
+            assert_eq!(select_batteries("5995", 1), vec![1]);
+        }
+
+        #[test]
+        fn test_largest_digits() {
+            assert_eq!(largest_digits("987654321111111", 12), "987654321111");
+            assert_eq!(largest_digits("818181911112111", 12), "888911112111");
+            assert_eq!(largest_digits("12345", 5), "12345");
+            assert_eq!(largest_digits("12345", 0), "");
+            assert_eq!(largest_digits("5445", 2), "55");
+            assert_eq!(largest_digits(&"19".repeat(1000), 1000), "9".repeat(1000));
+        }
+
+        /// Picks the batteries one by one, scanning the batteries that leave
+        /// enough of them for the next picks.
+        fn largest_joltage_reference(bank: &str, number_battery: usize) -> String {
+            let mut slice = bank;
+            let mut result = String::new();
+            for battery_index in 0..number_battery {
+                let (index, digit) = slice
+                    .char_indices()
+                    .rev()
+                    .skip(number_battery - battery_index - 1)
+                    .max_by_key(|x| x.1)
+                    .unwrap();
+                result.push(digit);
+                slice = &slice[index + 1..];
+            }
+            result
+        }
+
+        #[test]
+        fn test_against_reference() {
+            let rng = &mut Rng::new(0);
+            for _ in 0..500 {
+                let length = rng.range(1..=200) as usize;
+                let bank = generate::day03_bank(rng, length);
+                let count = rng.range(1..=bank.len() as u64) as usize;
+                assert_eq!(
+                    largest_digits(&bank, count),
+                    largest_joltage_reference(&bank, count),
+                    "Bank {} with {} batteries",
+                    bank,
+                    count
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{
+    Solution,
+    grid::{Grid, Position},
+    parse::ParseError,
+};
+
+pub struct Day04;
+
+fn parse_input(input: &str) -> Result<Grid<bool>, ParseError> {
+    Grid::parse_with(Day04::DAY, input, |c| match c {
+        '@' => Ok(true),
+        '.' => Ok(false),
+        _ => Err(format!("Unknown character '{}'", c)),
+    })
+}
+
+fn accessible(position: Position, data: &Grid<bool>) -> bool {
+    let adjacent = data
+        .neighbours8(position)
+        .filter(|&neighbour| data[neighbour])
+        .count();
+
+    adjacent < 4
+}
+
+fn part1(data: &Grid<bool>) -> usize {
+    data.positions()
+        .filter(|&position| data[position] && accessible(position, data))
+        .count()
+}
+
+fn part2(data: &Grid<bool>) -> usize {
+    let mut data = data.clone();
+    let mut result = 0;
+    loop {
+        let remove: Vec<Position> = data
+            .positions()
+            .filter(|&position| data[position] && accessible(position, &data))
+            .collect();
+
+        if remove.is_empty() {
+            break;
+        }
+
+        result += remove.len();
+
+        for position in remove {
+            data[position] = false;
+        }
+    }
+    result
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Data = Grid<bool>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Answer1 {
+        part1(data)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Answer2 {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exemple_day4_part1() {
+        let input = include_str!("../../examples/04.txt");
+        let data = parse_input(input).unwrap();
+        assert_eq!(part1(&data), 13);
+    }
+
+    #[test]
+    fn test_exemple_day4_part2() {
+        let input = include_str!("../../examples/04.txt");
+        let data = parse_input(input).unwrap();
+        assert_eq!(part2(&data), 43);
+    }
+}
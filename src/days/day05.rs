@@ -0,0 +1,170 @@
+use crate::{
+    Solution,
+    parse::{ParseError, parse_number},
+};
+
+pub struct Day05;
+
+#[derive(Debug, Clone)]
+pub struct Range {
+    start: u64,
+    end: u64,
+}
+
+#[derive(Debug)]
+pub struct Data {
+    ranges: Vec<Range>,
+    ingredients: Vec<u64>,
+}
+
+fn parse_input(input: &str) -> Result<Data, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    let mut index = 0;
+    let mut ranges = Vec::new();
+    while index < lines.len() && !lines[index].is_empty() {
+        let Some((start_str, end_str)) = lines[index].split_once('-') else {
+            return Err(ParseError::at(
+                Day05::DAY,
+                input,
+                lines[index],
+                format!("Invalid range '{}'", lines[index]),
+            ));
+        };
+        ranges.push(Range {
+            start: parse_number(Day05::DAY, input, start_str)?,
+            end: parse_number(Day05::DAY, input, end_str)?,
+        });
+        index += 1;
+    }
+
+    if index == lines.len() {
+        return Err(ParseError::at_offset(
+            Day05::DAY,
+            input,
+            input.len(),
+            0,
+            "Missing empty line before the ingredients",
+        ));
+    }
+    index += 1; // Skip the empty line
+
+    let ingredients: Vec<u64> = lines[index..]
+        .iter()
+        .map(|line| parse_number(Day05::DAY, input, line))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Data {
+        ranges,
+        ingredients,
+    })
+}
+
+fn part1(data: &Data) -> u64 {
+    data.ingredients
+        .iter()
+        .filter(|ingredient| {
+            data.ranges
+                .iter()
+                .any(|range| range.start <= **ingredient && **ingredient <= range.end)
+        })
+        .count() as u64
+}
+
+fn part2(mut ranges: Vec<Range>) -> u64 {
+    let mut result = 0;
+    let mut cursor = 0;
+
+    ranges.sort_by_key(|r| r.start);
+    for range in ranges {
+        let start = cursor.max(range.start);
+        if start <= range.end {
+            result += range.end - start + 1;
+        }
+        cursor = cursor.max(range.end + 1);
+    }
+    result
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Data = Data;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Answer1 {
+        part1(data)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Answer2 {
+        part2(data.ranges.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::Rng;
+
+    #[test]
+    fn test_example_day5_part1() {
+        let input = include_str!("../../examples/05.txt");
+        let data = parse_input(input).unwrap();
+        assert_eq!(part1(&data), 3);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("3-5\n10-1x\n\n1\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.text, "1x");
+
+        let error = parse_input("3-5\n10-14\n").unwrap_err();
+        assert_eq!(error.message, "Missing empty line before the ingredients");
+    }
+
+    #[test]
+    fn test_example_day5_part2() {
+        let input = include_str!("../../examples/05.txt");
+        let data = parse_input(input).unwrap();
+        assert_eq!(part2(data.ranges), 14);
+    }
+
+    /// Checks every ID from 0 to the end of the last range one by one.
+    fn part2_reference(ranges: &[Range]) -> u64 {
+        let last = ranges.iter().map(|range| range.end).max().unwrap_or(0);
+        (0..=last)
+            .filter(|id| {
+                ranges
+                    .iter()
+                    .any(|range| range.start <= *id && *id <= range.end)
+            })
+            .count() as u64
+    }
+
+    #[test]
+    fn test_part2_against_reference() {
+        for seed in 0..2000 {
+            let rng = &mut Rng::new(seed);
+            let ranges: Vec<Range> = (0..rng.range(1..=10))
+                .map(|_| {
+                    let start = rng.range(0..=100);
+                    Range {
+                        start,
+                        end: start + rng.range(0..=20),
+                    }
+                })
+                .collect();
+            assert_eq!(
+                part2(ranges.clone()),
+                part2_reference(&ranges),
+                "Seed {}",
+                seed
+            );
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use crate::{
+    Solution,
+    grid::Grid,
+    parse::{self, ParseError, parse_number},
+};
+
+pub struct Day06;
+
+type Data = Vec<(Vec<u64>, char)>;
+
+/// The worksheet read row by row for part 1 and column by column for part 2.
+pub struct Worksheet {
+    rows: Data,
+    columns: Data,
+}
+
+fn parse_input(input: &str) -> Result<Data, ParseError> {
+    let mut table: Vec<Vec<&str>> = input
+        .lines()
+        .map(|line| line.split_whitespace().collect())
+        .collect();
+    let Some(operations) = table.pop() else {
+        return Err(ParseError::at_offset(
+            Day06::DAY,
+            input,
+            0,
+            0,
+            "Empty input",
+        ));
+    };
+    let operations: Vec<char> = operations
+        .into_iter()
+        .map(|operation| match operation {
+            "+" | "*" => Ok(operation.chars().next().unwrap()),
+            _ => Err(ParseError::at(
+                Day06::DAY,
+                input,
+                operation,
+                format!("Invalid operation '{}'", operation),
+            )),
+        })
+        .collect::<Result<_, _>>()?;
+
+    let mut data = Vec::new();
+    for (index, &operation) in operations.iter().enumerate() {
+        data.push((
+            table
+                .iter()
+                .map(|row| match row.get(index) {
+                    Some(number) => parse_number(Day06::DAY, input, number),
+                    None => Err(missing_number(input, row)),
+                })
+                .collect::<Result<_, _>>()?,
+            operation,
+        ));
+    }
+    Ok(data)
+}
+
+/// Error for a row of the worksheet with less numbers than operations.
+fn missing_number(input: &str, row: &[&str]) -> ParseError {
+    let offset = row
+        .last()
+        .map_or(0, |number| parse::offset_of(input, number) + number.len());
+    ParseError::at_offset(Day06::DAY, input, offset, 0, "Missing number")
+}
+
+fn parse_input_part2(input: &str) -> Result<Data, ParseError> {
+    // Create a 2D vector to hold the characters
+    let table: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+
+    // Pad all the rows to the same length
+    let Some(length) = table.iter().map(|line| line.len()).max() else {
+        return Err(ParseError::at_offset(
+            Day06::DAY,
+            input,
+            0,
+            0,
+            "Empty input",
+        ));
+    };
+    let table = Grid::from_rows(
+        table
+            .into_iter()
+            .map(|mut line| {
+                line.resize(length, ' ');
+                line
+            })
+            .collect(),
+    )
+    .expect("The rows are padded to the same length");
+
+    // Transpose the table to read it column by column
+    let columns: Vec<String> = table
+        .transpose()
+        .rows()
+        .map(|column| column.iter().collect())
+        .collect();
+
+    let mut result: Data = Vec::new();
+    let mut index = 0;
+    for problem in columns.split(|c| c.trim().is_empty()) {
+        // Blank columns in a row or at the end leave empty problems
+        if problem.is_empty() {
+            index += 1;
+            continue;
+        }
+        let numbers = problem
+            .iter()
+            .zip(index..)
+            .map(|(column, index)| {
+                // The last character is the operation
+                let mut number = column.chars();
+                number.next_back();
+                number
+                    .as_str()
+                    .trim()
+                    .parse()
+                    .map_err(|_| column_error(input, index, "Invalid number in column"))
+            })
+            .collect::<Result<_, _>>()?;
+        let operation = problem[0]
+            .chars()
+            .last()
+            .filter(|operation| matches!(operation, '+' | '*'))
+            .ok_or_else(|| column_error(input, index, "Invalid operation in column"))?;
+        result.push((numbers, operation));
+        index += problem.len() + 1;
+    }
+
+    Ok(result)
+}
+
+/// Error located at the top of a column of the worksheet.
+fn column_error(input: &str, column: usize, message: &str) -> ParseError {
+    let first_line = input.lines().next().unwrap_or_default();
+    let offset = first_line
+        .char_indices()
+        .nth(column)
+        .map_or(first_line.len(), |(offset, _)| offset);
+    ParseError::at_offset(Day06::DAY, input, offset, 1, message)
+}
+
+fn part1(data: &Data) -> u64 {
+    data.iter()
+        .map(|(numbers, operator)| match operator {
+            '+' => numbers.iter().sum(),
+            '*' => numbers.iter().product(),
+            _ => 0,
+        })
+        .sum()
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Data = Worksheet;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        Ok(Worksheet {
+            rows: parse_input(input)?,
+            columns: parse_input_part2(input)?,
+        })
+    }
+
+    fn part1(data: &Self::Data) -> Self::Answer1 {
+        part1(&data.rows)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Answer2 {
+        part1(&data.columns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_day6_part1() {
+        let input = include_str!("../../examples/06.txt");
+        let data = parse_input(input).unwrap();
+        assert_eq!(part1(&data), 4277556);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("1 2\n3\n+ *\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.message, "Missing number");
+
+        let error = parse_input("1 2\n3 4\n+ -\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 3));
+        assert_eq!(error.message, "Invalid operation '-'");
+
+        let error = parse_input_part2("1 2\n3 4\n+ -\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+
+        // Several blank columns in a row, or a blank column at the end
+        let problems = vec![(vec![1], '+'), (vec![2], '*')];
+        assert_eq!(parse_input_part2("1  2\n+  *\n").unwrap(), problems);
+        assert_eq!(parse_input_part2("1 2 \n+ * \n").unwrap(), problems);
+        let error = parse_input_part2("1  2\n+  -\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 4));
+    }
+
+    #[test]
+    fn test_example_day6_part2() {
+        let input = include_str!("../../examples/06.txt");
+        let data = parse_input_part2(input).unwrap();
+        assert_eq!(part1(&data), 3263827);
+    }
+}
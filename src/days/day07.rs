@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+
+use crate::{Solution, grid::Grid, parse::ParseError};
+
+pub struct Day07;
+
+type Data = Grid<State>;
+type Memoize = HashMap<(usize, usize), u64>;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum State {
+    Start,
+    Beam,
+    Empty,
+    Splitter,
+}
+
+impl TryFrom<char> for State {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'S' => Ok(State::Start),
+            '|' => Ok(State::Beam),
+            '.' => Ok(State::Empty),
+            '^' => Ok(State::Splitter),
+            _ => Err(format!("Unknown state character '{}'", c)),
+        }
+    }
+}
+
+impl From<State> for char {
+    fn from(s: State) -> Self {
+        match s {
+            State::Start => 'S',
+            State::Beam => '|',
+            State::Empty => '.',
+            State::Splitter => '^',
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Data, ParseError> {
+    // The beams are computed, the input only has the manifold
+    let data: Data = Grid::parse_with(Day07::DAY, input, |c| match c {
+        '|' => Err("Unexpected beam '|' in the input".to_string()),
+        _ => State::try_from(c),
+    })?;
+
+    if data.height() == 0 || !data.row(0).contains(&State::Start) {
+        return Err(ParseError::at_offset(
+            Day07::DAY,
+            input,
+            0,
+            input.lines().next().map_or(0, str::len),
+            "No start found in the first row",
+        ));
+    }
+
+    // A splitter on a side would send a beam out of the manifold
+    for line in input.lines() {
+        let sides = [0, line.len().saturating_sub(1)];
+        if let Some(&column) = sides
+            .iter()
+            .find(|&&column| line[column..].starts_with('^'))
+        {
+            return Err(ParseError::at(
+                Day07::DAY,
+                input,
+                &line[column..column + 1],
+                "Splitter on a side of the manifold",
+            ));
+        }
+    }
+    Ok(data)
+}
+
+fn part1(data: &Data) -> u32 {
+    let mut data = data.clone();
+
+    // Propagate beams downwards
+    let mut splits = 0u32;
+    for line in 1..data.height() {
+        for column in 1..data.width() - 1 {
+            // Match on (top_left, top, top_right, left, self, right)
+            data[(line, column)] = match (
+                data[(line - 1, column - 1)],
+                data[(line - 1, column)],
+                data[(line - 1, column + 1)],
+                data[(line, column - 1)],
+                data[(line, column)],
+                data[(line, column + 1)],
+            ) {
+                (_, State::Start, _, _, State::Empty, _) => State::Beam,
+                (_, _, State::Beam, _, State::Empty, State::Splitter) => State::Beam,
+                (State::Beam, _, _, State::Splitter, State::Empty, _) => State::Beam,
+                (_, State::Beam, _, _, State::Empty, _) => State::Beam,
+                (_, State::Beam, _, _, State::Splitter, _) => {
+                    splits += 1;
+                    State::Splitter
+                }
+                _ => data[(line, column)],
+            }
+        }
+    }
+    splits
+}
+
+fn part2_recursive(data: &Data, line: usize, column: usize, memoize: &mut Memoize) -> u64 {
+    if data.height() <= line {
+        return 1;
+    }
+
+    if let Some(memoized_result) = memoize.get(&(line, column)) {
+        return *memoized_result;
+    }
+
+    let result = match data[(line, column)] {
+        State::Empty | State::Start => part2_recursive(data, line + 1, column, memoize),
+        State::Splitter => {
+            part2_recursive(data, line + 1, column - 1, memoize)
+                + part2_recursive(data, line + 1, column + 1, memoize)
+        }
+        State::Beam => unreachable!("Beams are rejected when parsing"),
+    };
+
+    memoize.insert((line, column), result);
+    result
+}
+
+fn part2(data: &Data) -> u64 {
+    let mut memoize: HashMap<(usize, usize), u64> = HashMap::new();
+
+    let start_column = data
+        .row(0)
+        .iter()
+        .position(|&s| s == State::Start)
+        .expect("No start found in the first row");
+    part2_recursive(data, 0, start_column, &mut memoize)
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Data = Data;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Answer1 {
+        part1(data)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Answer2 {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day7_part1() {
+        let input = include_str!("../../examples/07.txt");
+        let data = parse_input(input).unwrap();
+        assert_eq!(part1(&data), 21);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("..S..\n..^..\n.x...\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 2));
+        assert_eq!(error.message, "Unknown state character 'x'");
+
+        let error = parse_input(".....\n..^..\n").unwrap_err();
+        assert_eq!(error.message, "No start found in the first row");
+
+        let error = parse_input("..S..\n..|..\n.....\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.message, "Unexpected beam '|' in the input");
+
+        let error = parse_input("..S\n..^\n...\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.message, "Splitter on a side of the manifold");
+
+        let error = parse_input("S..\n^..\n...\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.message, "Splitter on a side of the manifold");
+    }
+
+    #[test]
+    fn test_day7_part2() {
+        let input = include_str!("../../examples/07.txt");
+        let data = parse_input(input).unwrap();
+        assert_eq!(part2(&data), 40);
+    }
+}
@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+
+use crate::{
+    Solution,
+    parse::{ParseError, parse_number},
+};
+
+pub struct Day08;
+
+type CircuitId = u16;
+type BoxId = u16;
+type Position = (u64, u64, u64);
+
+#[derive(Debug, Clone)]
+pub struct Box {
+    circuit_id: CircuitId,
+    position: Position,
+}
+
+#[derive(Debug, Clone)]
+pub struct Data {
+    boxes: Vec<Box>,
+    circuits: HashMap<CircuitId, Vec<BoxId>>,
+}
+
+fn parse_input(input: &str) -> Result<Data, ParseError> {
+    let boxes: Vec<Box> = input
+        .lines()
+        .enumerate()
+        .map(|(id, line)| {
+            if id > BoxId::MAX as usize {
+                return Err(ParseError::at(
+                    Day08::DAY,
+                    input,
+                    line,
+                    format!("Too many boxes, at most {}", BoxId::MAX as usize + 1),
+                ));
+            }
+            let coordinates = line
+                .split(',')
+                .map(|s| parse_number::<u64>(Day08::DAY, input, s))
+                .collect::<Result<Vec<_>, _>>()?;
+            let [x, y, z] = coordinates[..] else {
+                return Err(ParseError::at(
+                    Day08::DAY,
+                    input,
+                    line,
+                    format!("Expected 3 coordinates, found {}", coordinates.len()),
+                ));
+            };
+            Ok(Box {
+                circuit_id: id as CircuitId,
+                position: (x, y, z),
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    let circuits: HashMap<CircuitId, Vec<BoxId>> = boxes
+        .iter()
+        .map(|b| (b.circuit_id, vec![b.circuit_id]))
+        .collect();
+
+    Ok(Data { boxes, circuits })
+}
+
+fn calculate_distance(box1: &Box, box2: &Box) -> u64 {
+    let dx = (box1.position.0 as i64 - box2.position.0 as i64).unsigned_abs();
+    let dy = (box1.position.1 as i64 - box2.position.1 as i64).unsigned_abs();
+    let dz = (box1.position.2 as i64 - box2.position.2 as i64).unsigned_abs();
+    dx * dx + dy * dy + dz * dz
+}
+
+fn part1(mut data: Data, connections: usize) -> usize {
+    let mut pairs: Vec<(BoxId, BoxId, u64)> = Vec::new();
+    for index1 in 0..data.boxes.len() {
+        for index2 in (index1 + 1)..data.boxes.len() {
+            pairs.push((
+                index1 as BoxId,
+                index2 as BoxId,
+                calculate_distance(&data.boxes[index1], &data.boxes[index2]),
+            ));
+        }
+    }
+
+    pairs.sort_by_key(|&(_, _, dist_a)| dist_a);
+
+    for (box_id1, box_id2, _) in pairs.iter().take(connections) {
+        let to_circuit_id = data.boxes[*box_id1 as usize].circuit_id;
+        let from_circuit_id = data.boxes[*box_id2 as usize].circuit_id;
+
+        if to_circuit_id == from_circuit_id {
+            continue;
+        }
+
+        for box_id in data.circuits.get(&from_circuit_id).unwrap().iter() {
+            data.boxes[*box_id as usize].circuit_id = to_circuit_id;
+        }
+
+        if let Some(from_circuit) = data.circuits.remove(&from_circuit_id) {
+            data.circuits
+                .get_mut(&to_circuit_id)
+                .unwrap()
+                .extend(from_circuit);
+        }
+    }
+
+    let mut lengths: Vec<usize> = data
+        .circuits
+        .values()
+        .map(|circuit| circuit.len())
+        .collect();
+    lengths.sort();
+    lengths.iter().rev().take(3).product()
+}
+
+fn part2(mut data: Data) -> u64 {
+    let mut pairs: Vec<(BoxId, BoxId, u64)> = Vec::new();
+    for index1 in 0..data.boxes.len() {
+        for index2 in (index1 + 1)..data.boxes.len() {
+            pairs.push((
+                index1 as BoxId,
+                index2 as BoxId,
+                calculate_distance(&data.boxes[index1], &data.boxes[index2]),
+            ));
+        }
+    }
+
+    pairs.sort_by_key(|&(_, _, dist_a)| dist_a);
+
+    for (box_id1, box_id2, _) in pairs.iter() {
+        let to_circuit_id = data.boxes[*box_id1 as usize].circuit_id;
+        let from_circuit_id = data.boxes[*box_id2 as usize].circuit_id;
+
+        if to_circuit_id == from_circuit_id {
+            continue;
+        }
+
+        for box_id in data.circuits.get(&from_circuit_id).unwrap().iter() {
+            data.boxes[*box_id as usize].circuit_id = to_circuit_id;
+        }
+
+        if let Some(from_circuit) = data.circuits.remove(&from_circuit_id) {
+            data.circuits
+                .get_mut(&to_circuit_id)
+                .unwrap()
+                .extend(from_circuit);
+        }
+
+        if data.circuits.get(&to_circuit_id).unwrap().len() == data.boxes.len() {
+            return data.boxes[*box_id1 as usize].position.0
+                * data.boxes[*box_id2 as usize].position.0;
+        }
+    }
+
+    unreachable!()
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Data = Data;
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Answer1 {
+        part1(data.clone(), 1000)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Answer2 {
+        part2(data.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = include_str!("../../examples/08.txt");
+        let data = parse_input(input).unwrap();
+        assert_eq!(part1(data, 10), 40);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("162,817,812\n57,618\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.message, "Expected 3 coordinates, found 2");
+
+        let error = parse_input("162,817,812\n57,-618,57\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+
+        let input = "1,2,3\n".repeat(65_536);
+        assert!(parse_input(&input).is_ok());
+        let error = parse_input(&(input + "4,5,6\n")).unwrap_err();
+        assert_eq!((error.line, error.column), (65_537, 1));
+        assert_eq!(error.message, "Too many boxes, at most 65536");
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = include_str!("../../examples/08.txt");
+        let data = parse_input(input).unwrap();
+        assert_eq!(part2(data), 25272);
+    }
+}
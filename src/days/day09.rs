@@ -0,0 +1,329 @@
+use std::ops::Range;
+
+use itertools::Itertools;
+
+use crate::{
+    Solution,
+    grid::{Grid, Position},
+    parse::{ParseError, parse_number},
+};
+
+pub struct Day09;
+
+fn parse_input(input: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let Some((x, y)) = line.split_once(',') else {
+                return Err(ParseError::at(
+                    Day09::DAY,
+                    input,
+                    line,
+                    "Expected two coordinates separated by a comma",
+                ));
+            };
+            Ok((
+                parse_number(Day09::DAY, input, x)?,
+                parse_number(Day09::DAY, input, y)?,
+            ))
+        })
+        .collect()
+}
+
+fn part1(data: &[(u64, u64)]) -> u64 {
+    let mut max_area = u64::MIN;
+    for index1 in 0..data.len() {
+        for index2 in (index1 + 1)..data.len() {
+            let area = (data[index1].0.abs_diff(data[index2].0) + 1)
+                * (data[index1].1.abs_diff(data[index2].1) + 1);
+            max_area = u64::max(max_area, area);
+        }
+    }
+    max_area
+}
+
+/// Compacts the coordinates: each coordinate gets its own cell, and so does
+/// each non-empty gap between two consecutive coordinates.
+fn compact<I>(indices: I) -> Vec<Range<u64>>
+where
+    I: Iterator<Item = u64>,
+{
+    let indices: Vec<u64> = indices.sorted().dedup().collect();
+    let mut compaction = Vec::with_capacity(2 * indices.len());
+    for (index, &coord) in indices.iter().enumerate() {
+        compaction.push(coord..coord + 1);
+        if let Some(&next) = indices.get(index + 1)
+            && coord + 1 < next
+        {
+            compaction.push(coord + 1..next);
+        }
+    }
+    compaction
+}
+
+/// Index of the cell containing `coord` in the compacted grid, which has a
+/// border of empty cells around the compaction.
+fn downgrade(compaction: &[Range<u64>], coord: u64) -> usize {
+    compaction
+        .binary_search_by(|range| {
+            if range.contains(&coord) {
+                std::cmp::Ordering::Equal
+            } else if range.start > coord {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Less
+            }
+        })
+        .unwrap()
+        + 1
+}
+
+fn between(row1: usize, col1: usize, row2: usize, col2: usize) -> impl Iterator<Item = Position> {
+    if row1 == row2 {
+        let start = col1.min(col2);
+        let end = col1.max(col2);
+        ((start + 1)..end)
+            .map(move |col| (row1, col))
+            .collect::<Vec<_>>()
+            .into_iter()
+    } else if col1 == col2 {
+        let start = row1.min(row2);
+        let end = row1.max(row2);
+        ((start + 1)..end)
+            .map(move |row| (row, col1))
+            .collect::<Vec<_>>()
+            .into_iter()
+    } else {
+        panic!("Not a straight line");
+    }
+}
+
+/// Marks the empty cells reachable from `start` as outside (`O`).
+fn fill(grid: &mut Grid<char>, start: Position) {
+    let mut stack = vec![start];
+
+    grid[start] = 'O';
+
+    while let Some(position) = stack.pop() {
+        let empty: Vec<Position> = grid
+            .neighbours4(position)
+            .filter(|&neighbour| grid[neighbour] == '.')
+            .collect();
+        for neighbour in empty {
+            grid[neighbour] = 'O';
+            stack.push(neighbour);
+        }
+    }
+}
+
+fn part2(data: &[(u64, u64)]) -> u64 {
+    // Create the compactions for rows and columns
+    let rows_compaction = compact(data.iter().map(|(row, _)| *row));
+    let cols_compaction = compact(data.iter().map(|(_, col)| *col));
+
+    // Create the compacted grid and add the red and greed tiles
+    let mut grid = Grid::new(cols_compaction.len() + 2, rows_compaction.len() + 2, '.');
+    for ((row1, col1), (row2, col2)) in data.iter().chain(data.first()).tuple_windows() {
+        let crow1 = downgrade(&rows_compaction, *row1);
+        let ccol1 = downgrade(&cols_compaction, *col1);
+        let crow2 = downgrade(&rows_compaction, *row2);
+        let ccol2 = downgrade(&cols_compaction, *col2);
+        grid[(crow1, ccol1)] = '#';
+
+        for position in between(crow1, ccol1, crow2, ccol2) {
+            grid[position] = 'X';
+        }
+    }
+
+    // Fill the area outside of the loop, starting from the border
+    fill(&mut grid, (0, 0));
+
+    // Create the prefix map
+    let mut px_map: Vec<Vec<u64>> = Vec::with_capacity(grid.height());
+    for row in 0..grid.height() {
+        px_map.push(Vec::with_capacity(grid.width()));
+        for col in 0..grid.width() {
+            let mut value = 0;
+            if row > 0 {
+                value += px_map[row - 1][col];
+            }
+            if col > 0 {
+                value += px_map[row][col - 1];
+            }
+            if row > 0 && col > 0 {
+                value -= px_map[row - 1][col - 1];
+            }
+            if grid[(row, col)] == 'O' {
+                value += 1;
+            }
+            px_map[row].push(value);
+        }
+    }
+
+    // Find valid squares
+    let mut max_area = u64::MIN;
+    for index1 in 0..data.len() {
+        for index2 in (index1 + 1)..data.len() {
+            let top = data[index1].0.min(data[index2].0);
+            let bottom = data[index1].0.max(data[index2].0);
+            let left = data[index1].1.min(data[index2].1);
+            let right = data[index1].1.max(data[index2].1);
+
+            let area = (bottom - top + 1) * (right - left + 1);
+            if max_area < area {
+                // The area is bigger than the best so far. Check if it's valid.
+                // The border is outside, so the rectangle never touches the
+                // first row or column.
+                let ctop = downgrade(&rows_compaction, top);
+                let cbottom = downgrade(&rows_compaction, bottom);
+                let cleft = downgrade(&cols_compaction, left);
+                let cright = downgrade(&cols_compaction, right);
+
+                // Count the invalid cells in the rectangle using the prefix map:
+                // the areas above and to the left, minus the area above-left
+                // that's counted twice.
+                let invalid_count = px_map[ctop - 1][cright] + px_map[cbottom][cleft - 1]
+                    - px_map[ctop - 1][cleft - 1];
+
+                // If the invalid count at the bottom-right corner equals the total
+                // invalid count above and to the left it means there is no invalid cell
+                // inside the rectangle.
+                if invalid_count == px_map[cbottom][cright] {
+                    max_area = area;
+                }
+            }
+        }
+    }
+    max_area
+}
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Data = Vec<(u64, u64)>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Answer1 {
+        part1(data)
+    }
+
+    fn part2(data: &Self::Data) -> Self::Answer2 {
+        part2(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::{self, Rng};
+
+    #[test]
+    fn test_example_day9_part1() {
+        let input = include_str!("../../examples/09.txt");
+        let data = parse_input(input).unwrap();
+        assert_eq!(part1(&data), 50);
+    }
+
+    #[test]
+    fn test_example_day9_part2() {
+        let input = include_str!("../../examples/09.txt");
+        let data = parse_input(input).unwrap();
+        assert_eq!(part2(&data), 24);
+    }
+
+    /// Counts the tiles of every rectangle one by one.
+    fn part1_reference(data: &[(u64, u64)]) -> u64 {
+        data.iter()
+            .tuple_combinations()
+            .map(|(&(row1, col1), &(row2, col2))| {
+                let rows = (row1.min(row2)..=row1.max(row2)).count();
+                let cols = (col1.min(col2)..=col1.max(col2)).count();
+                (rows * cols) as u64
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Checks every tile of every rectangle: the tiles must all be on the loop
+    /// or inside it.
+    fn part2_reference(data: &[(u64, u64)]) -> u64 {
+        let edges: Vec<_> = data
+            .iter()
+            .copied()
+            .zip(data.iter().copied().cycle().skip(1))
+            .collect();
+        let height = data.iter().map(|&(row, _)| row).max().unwrap() as usize + 1;
+        let width = data.iter().map(|&(_, col)| col).max().unwrap() as usize + 1;
+
+        let mut valid = Grid::new(width, height, false);
+        for (row, col) in valid.positions() {
+            let (row, col) = (row as u64, col as u64);
+            let on_loop = edges.iter().any(|&((row1, col1), (row2, col2))| {
+                (row1.min(row2)..=row1.max(row2)).contains(&row)
+                    && (col1.min(col2)..=col1.max(col2)).contains(&col)
+            });
+            // Cast a ray to the right, and count the vertical edges it crosses
+            let crossings = edges
+                .iter()
+                .filter(|&&((row1, col1), (row2, col2))| {
+                    col1 == col2 && col < col1 && (row1.min(row2)..row1.max(row2)).contains(&row)
+                })
+                .count();
+            valid[(row as usize, col as usize)] = on_loop || crossings % 2 == 1;
+        }
+
+        data.iter()
+            .tuple_combinations()
+            .filter(|&(&(row1, col1), &(row2, col2))| {
+                (row1.min(row2)..=row1.max(row2)).all(|row| {
+                    (col1.min(col2)..=col1.max(col2)).all(|col| valid[(row as usize, col as usize)])
+                })
+            })
+            .map(|(&(row1, col1), &(row2, col2))| {
+                (row1.abs_diff(row2) + 1) * (col1.abs_diff(col2) + 1)
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Generates a polygon, then moves its coordinates closer together without
+    /// changing their order, so the reference can check every tile.
+    fn small_polygon(seed: u64) -> Vec<(u64, u64)> {
+        let rng = &mut Rng::new(seed);
+        let size = rng.range(1..=4) as usize;
+        let data = parse_input(&generate::day09(rng, size)).unwrap();
+
+        let mut shrink = |coords: Vec<u64>| {
+            let mut next = 1;
+            coords
+                .into_iter()
+                .sorted()
+                .dedup()
+                .map(|coord| {
+                    let small = next;
+                    next += rng.range(1..=3);
+                    (coord, small)
+                })
+                .collect::<std::collections::HashMap<_, _>>()
+        };
+        let rows = shrink(data.iter().map(|&(row, _)| row).collect());
+        let cols = shrink(data.iter().map(|&(_, col)| col).collect());
+        data.iter()
+            .map(|(row, col)| (rows[row], cols[col]))
+            .collect()
+    }
+
+    #[test]
+    fn test_against_reference() {
+        for seed in 0..1000 {
+            let data = small_polygon(seed);
+            assert_eq!(part1(&data), part1_reference(&data), "Seed {}", seed);
+            assert_eq!(part2(&data), part2_reference(&data), "Seed {}", seed);
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use nom::{
+    IResult, Parser,
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, space1},
+    combinator::{all_consuming, consumed, recognize, value},
+    multi::{many1, separated_list1},
+    sequence::{delimited, terminated},
+};
+
+use crate::{
+    Solution, Unsolved,
+    parse::{self, ParseError},
+};
+
+pub struct Day10;
+
+#[derive(Debug, Clone)]
+enum Light {
+    On,
+    Off,
+}
+
+#[derive(Debug, Clone)]
+pub struct Machine {
+    light_diagram: u32,
+    buttons: Vec<u32>,
+}
+
+fn parse_light(input: &str) -> IResult<&str, Light> {
+    alt((value(Light::Off, tag(".")), value(Light::On, tag("#")))).parse(input)
+}
+
+fn parse_light_diagram(input: &str) -> IResult<&str, (&str, Vec<Light>)> {
+    consumed(delimited(tag("["), many1(parse_light), tag("]"))).parse(input)
+}
+
+/// Parses the indices of the lights toggled by a button, with their text.
+fn parse_button(input: &str) -> IResult<&str, Vec<(&str, u32)>> {
+    delimited(
+        tag("("),
+        separated_list1(tag(","), consumed(complete::u32)),
+        tag(")"),
+    )
+    .parse(input)
+}
+
+fn parse_buttons(input: &str) -> IResult<&str, Vec<Vec<(&str, u32)>>> {
+    separated_list1(space1, parse_button).parse(input)
+}
+
+fn parse_joltage(input: &str) -> IResult<&str, ()> {
+    let (input, _) = recognize(delimited(
+        tag("{"),
+        separated_list1(tag(","), complete::u32),
+        tag("}"),
+    ))
+    .parse(input)?;
+    Ok((input, ()))
+}
+
+type RawMachine<'a> = ((&'a str, Vec<Light>), Vec<Vec<(&'a str, u32)>>);
+
+fn parse_machine(input: &str) -> IResult<&str, RawMachine<'_>> {
+    let (input, (light_diagram, buttons, _joltages)) = (
+        terminated(parse_light_diagram, space1),
+        terminated(parse_buttons, space1),
+        parse_joltage,
+    )
+        .parse(input)?;
+    Ok((input, (light_diagram, buttons)))
+}
+
+/// Builds the masks of the machine, checking that the lights fit in a `u32`
+/// and that the buttons only toggle lights of the diagram.
+fn build_machine(input: &str, machine: RawMachine) -> Result<Machine, ParseError> {
+    let ((diagram, lights), buttons) = machine;
+    if lights.len() > u32::BITS as usize {
+        return Err(ParseError::at(
+            Day10::DAY,
+            input,
+            diagram,
+            format!("Too many lights, at most {}", u32::BITS),
+        ));
+    }
+
+    let light_diagram = lights
+        .iter()
+        .enumerate()
+        .fold(0u32, |acc, (i, light)| match light {
+            Light::On => acc | (1 << i),
+            Light::Off => acc,
+        });
+
+    let buttons = buttons
+        .into_iter()
+        .map(|indexes| {
+            indexes.into_iter().try_fold(0u32, |acc, (text, index)| {
+                if index as usize >= lights.len() {
+                    return Err(ParseError::at(
+                        Day10::DAY,
+                        input,
+                        text,
+                        format!(
+                            "Invalid light {}, the diagram has {} lights",
+                            index,
+                            lights.len()
+                        ),
+                    ));
+                }
+                Ok(acc | (1 << index))
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Machine {
+        light_diagram,
+        buttons,
+    })
+}
+
+fn part1_recursive(machine: &Machine, status: u32, depth: u32) -> bool {
+    if status == machine.light_diagram {
+        return true;
+    }
+
+    if depth == 0 {
+        return false;
+    }
+
+    for &button in &machine.buttons {
+        let new_status = status ^ button;
+        if part1_recursive(machine, new_status, depth - 1) {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn part1(data: &[Machine]) -> u32 {
+    let mut result = 0u32;
+    for machine in data {
+        let mut depth = 1u32;
+        loop {
+            if part1_recursive(machine, 0, depth) {
+                break;
+            }
+            depth += 1;
+        }
+        result += depth;
+    }
+    result
+}
+
+fn parse_input(input: &str) -> Result<Vec<Machine>, ParseError> {
+    input
+        .lines()
+        .map(|line| match all_consuming(parse_machine).parse(line) {
+            Ok((_, machine)) => build_machine(input, machine),
+            Err(nom::Err::Error(error) | nom::Err::Failure(error)) => {
+                let message = match error.input.chars().next() {
+                    Some(c) => format!("Unexpected character '{}'", c),
+                    None => "Unexpected end of line".to_string(),
+                };
+                let len = error.input.chars().next().map_or(0, char::len_utf8);
+                Err(ParseError::at_offset(
+                    Day10::DAY,
+                    input,
+                    parse::offset_of(input, error.input),
+                    len,
+                    message,
+                ))
+            }
+            Err(nom::Err::Incomplete(_)) => unreachable!("Only complete parsers are used"),
+        })
+        .collect()
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Data = Vec<Machine>;
+    type Answer1 = u32;
+    type Answer2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(data: &Self::Data) -> Self::Answer1 {
+        part1(data)
+    }
+
+    fn part2(_data: &Self::Data) -> Self::Answer2 {
+        Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_day10_part1() {
+        let input = include_str!("../../examples/10.txt");
+        let data = parse_input(input).unwrap();
+        assert_eq!(part1(&data), 7);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("[.##.] (3) (1,3) {3,5}\n[.#x.] (3) {3}\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.message, "Unexpected character 'x'");
+
+        let error = parse_input("[.##.] (3) (1,3)").unwrap_err();
+        assert_eq!(error.message, "Unexpected end of line");
+
+        let error = parse_input("[.#] (1) {1}\n[.#] (0,40) {1}\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 9));
+        assert_eq!(error.message, "Invalid light 40, the diagram has 2 lights");
+        let error = parse_input("[.##.] (4) {1}\n").unwrap_err();
+        assert_eq!(error.message, "Invalid light 4, the diagram has 4 lights");
+
+        let line = format!("[{}] (0) {{1}}\n", ".".repeat(33));
+        let error = parse_input(&line).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.message, "Too many lights, at most 32");
+        let line = format!("[{}#] (31) {{1}}\n", ".".repeat(31));
+        assert_eq!(part1(&parse_input(&line).unwrap()), 1);
+    }
+}
@@ -0,0 +1,443 @@
+//! Random puzzle inputs, to stress-test and benchmark the solutions beyond the
+//! examples.
+//!
+//! Every generator takes a seeded `Rng`, so the same seed always produces the
+//! same input, and a size whose meaning depends on the day.
+
+use std::{fmt::Display, ops::RangeInclusive};
+
+use itertools::Itertools;
+
+/// Small pseudo random number generator (SplitMix64). It's not suitable for
+/// cryptography, but it's fast and its sequence never changes for a seed.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number uniformly distributed in `range`.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "Empty range");
+        let span = (end - start) as u128 + 1;
+        start + ((self.next_u64() as u128 * span) >> 64) as u64
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        self.range(0..=len as u64 - 1) as usize
+    }
+
+    /// Returns true with the given probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    /// Returns `count` distinct numbers from `range`, sorted.
+    pub fn distinct(&mut self, range: RangeInclusive<u64>, count: usize) -> Vec<u64> {
+        assert!(
+            (range.end() - range.start()) as u128 + 1 >= count as u128,
+            "Not enough numbers in the range"
+        );
+        let mut numbers = std::collections::BTreeSet::new();
+        while numbers.len() < count {
+            numbers.insert(self.range(range.clone()));
+        }
+        numbers.into_iter().collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GenerateError {
+    /// There's no generator for the day.
+    NoGenerator(u8),
+    /// The size is above the largest one the day can generate.
+    TooLarge { day: u8, size: usize, max: usize },
+}
+
+impl Display for GenerateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GenerateError::NoGenerator(day) => write!(f, "No generator for day {}", day),
+            GenerateError::TooLarge { day, size, max } => write!(
+                f,
+                "Size {} is too large for day {}, at most {}",
+                size, day, max
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GenerateError {}
+
+/// Largest size the generator of a day supports, if it's limited.
+fn max_size(day: u8) -> Option<usize> {
+    match day {
+        // The boxes are identified with a u16
+        8 => Some(u16::MAX as usize + 1),
+        // 4 distinct rows per column, from 99999 rows
+        9 => Some(99_999 / 4),
+        _ => None,
+    }
+}
+
+/// Generates an input for the given day.
+pub fn generate(day: u8, size: usize, seed: u64) -> Result<String, GenerateError> {
+    if let Some(max) = max_size(day)
+        && size > max
+    {
+        return Err(GenerateError::TooLarge { day, size, max });
+    }
+
+    let rng = &mut Rng::new(seed);
+    let input = match day {
+        1 => day01(rng, size),
+        2 => day02(rng, size),
+        3 => day03(rng, size),
+        4 => day04(rng, size),
+        5 => day05(rng, size),
+        6 => day06(rng, size),
+        7 => day07(rng, size),
+        8 => day08(rng, size),
+        9 => day09(rng, size),
+        10 => day10(rng, size),
+        _ => return Err(GenerateError::NoGenerator(day)),
+    };
+    Ok(input)
+}
+
+/// `size` dial rotations like `L68`.
+pub fn day01(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let direction = if rng.chance(0.5) { 'L' } else { 'R' };
+            format!("{}{}\n", direction, rng.range(1..=999))
+        })
+        .collect()
+}
+
+/// `size` disjoint ranges of IDs like `11-22`, separated by commas. The ranges
+/// cover at most 10000 IDs.
+pub fn day02(rng: &mut Rng, size: usize) -> String {
+    let mut ranges = Vec::with_capacity(size);
+    for _ in 0..size {
+        let digits = rng.range(1..=10) as u32;
+        let start = rng.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1);
+        let end = start + rng.range(0..=9_999);
+        ranges.push((start, end));
+    }
+
+    // Shift the overlapping ranges after the previous ones
+    ranges.sort();
+    let mut next = 0;
+    for (start, end) in ranges.iter_mut() {
+        if *start < next {
+            *end += next - *start;
+            *start = next;
+        }
+        next = *end + 1;
+    }
+
+    ranges
+        .iter()
+        .map(|(start, end)| format!("{}-{}", start, end))
+        .join(",")
+        + "\n"
+}
+
+/// `size` banks of 100 batteries with joltages from 1 to 9.
+pub fn day03(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| day03_bank(rng, 100) + "\n").collect()
+}
+
+/// A bank of `length` batteries with joltages from 1 to 9.
+pub fn day03_bank(rng: &mut Rng, length: usize) -> String {
+    (0..length)
+        .map(|_| char::from(b'0' + rng.range(1..=9) as u8))
+        .collect()
+}
+
+/// A `size` by `size` grid of rolls of paper (`@`) and empty cells (`.`).
+pub fn day04(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let row: String = (0..size)
+                .map(|_| if rng.chance(0.6) { '@' } else { '.' })
+                .collect();
+            row + "\n"
+        })
+        .collect()
+}
+
+/// `size` ranges of fresh ingredients, an empty line, and `size` ingredients.
+pub fn day05(rng: &mut Rng, size: usize) -> String {
+    const MAX_ID: u64 = 1_000_000_000_000;
+
+    let mut input = String::new();
+    for _ in 0..size {
+        let start = rng.range(1..=MAX_ID);
+        let end = (start + rng.range(0..=MAX_ID / 100)).min(MAX_ID);
+        input += &format!("{}-{}\n", start, end);
+    }
+    input += "\n";
+    for _ in 0..size {
+        input += &format!("{}\n", rng.range(1..=MAX_ID));
+    }
+    input
+}
+
+/// A worksheet of `size` problems, each with 3 or 4 numbers of 1 to 3 digits.
+///
+/// The numbers of a problem are all aligned to the left or to the right, and
+/// their lengths are sorted, so reading the problem column by column never
+/// finds a space between two digits.
+pub fn day06(rng: &mut Rng, size: usize) -> String {
+    let rows = rng.range(3..=4) as usize;
+    let mut lines = vec![String::new(); rows + 1];
+    for problem in 0..size {
+        let mut lengths: Vec<usize> = (0..rows).map(|_| rng.range(1..=3) as usize).collect();
+        lengths.sort();
+        if rng.chance(0.5) {
+            lengths.reverse();
+        }
+        let width = *lengths.iter().max().unwrap();
+        let left = rng.chance(0.5);
+
+        for (line, length) in lines.iter_mut().zip(lengths) {
+            if problem > 0 {
+                line.push(' ');
+            }
+            let number: String = (0..length)
+                .map(|_| char::from(b'0' + rng.range(1..=9) as u8))
+                .collect();
+            if left {
+                line.push_str(&format!("{:<width$}", number));
+            } else {
+                line.push_str(&format!("{:>width$}", number));
+            }
+        }
+
+        let operations = &mut lines[rows];
+        if problem > 0 {
+            operations.push(' ');
+        }
+        let operation = if rng.chance(0.5) { '+' } else { '*' };
+        operations.push_str(&format!("{:<width$}", operation));
+    }
+    lines.iter().map(|line| line.clone() + "\n").collect()
+}
+
+/// A manifold with `size` rows of splitters. The manifold is wide enough for
+/// the beams to never reach its sides.
+pub fn day07(rng: &mut Rng, size: usize) -> String {
+    let width = 2 * size + 3;
+    let start = size + 1;
+
+    let mut lines = Vec::with_capacity(2 * size + 2);
+    let mut first = vec!['.'; width];
+    first[start] = 'S';
+    lines.push(first);
+    lines.push(vec!['.'; width]);
+
+    for row in 0..size {
+        // The beams can only be at every other column around the start
+        let mut line = vec!['.'; width];
+        for column in ((start - row)..=(start + row)).step_by(2) {
+            if rng.chance(0.6) {
+                line[column] = '^';
+            }
+        }
+        lines.push(line);
+        lines.push(vec!['.'; width]);
+    }
+
+    lines
+        .into_iter()
+        .map(|line| line.into_iter().collect::<String>() + "\n")
+        .collect()
+}
+
+/// `size` junction boxes with coordinates up to 100000. Since boxes are
+/// identified with a `u16`, `size` must be at most 65536.
+pub fn day08(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            format!(
+                "{},{},{}\n",
+                rng.range(0..=100_000),
+                rng.range(0..=100_000),
+                rng.range(0..=100_000)
+            )
+        })
+        .collect()
+}
+
+/// The red tiles at the corners of an orthogonal polygon spanning `size`
+/// columns (at least 1, at most 24999). Coordinates are between 1 and 99999.
+///
+/// Each column of the polygon goes from a bottom to a top row, and adjacent
+/// columns overlap, so the polygon never crosses itself.
+pub fn day09(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let xs = rng.distinct(1..=99_999, size + 1);
+    let ys = rng.distinct(1..=99_999, 4 * size);
+
+    // Pick the bottom and top rows of every column from the sorted rows, so
+    // that adjacent columns overlap and have different bottoms and tops.
+    let (mut bottoms, mut tops) = (Vec::new(), Vec::new());
+    let middle = ys.len() / 2;
+    for column in 0..size {
+        loop {
+            let bottom = ys[rng.index(middle)];
+            let top = ys[middle + rng.index(ys.len() - middle)];
+            if column == 0 || (bottoms[column - 1] != bottom && tops[column - 1] != top) {
+                bottoms.push(bottom);
+                tops.push(top);
+                break;
+            }
+        }
+    }
+
+    // Go right along the bottom, then left along the top
+    let mut corners = Vec::new();
+    for column in 0..size {
+        corners.push((xs[column], bottoms[column]));
+        corners.push((xs[column + 1], bottoms[column]));
+    }
+    for column in (0..size).rev() {
+        corners.push((xs[column + 1], tops[column]));
+        corners.push((xs[column], tops[column]));
+    }
+
+    corners
+        .iter()
+        .map(|(x, y)| format!("{},{}\n", x, y))
+        .collect()
+}
+
+/// `size` machines with 3 to 8 lights. The light diagram can always be
+/// reached by pressing at most 3 buttons, and the joltages are reached by
+/// pressing each button up to 10 times.
+pub fn day10(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let lights = rng.range(3..=8) as usize;
+            let buttons: Vec<Vec<usize>> = (0..rng.range(3..=8))
+                .map(|_| {
+                    let count = rng.range(1..=lights as u64);
+                    rng.distinct(0..=lights as u64 - 1, count as usize)
+                        .into_iter()
+                        .map(|light| light as usize)
+                        .collect()
+                })
+                .collect();
+
+            let mut diagram = vec![false; lights];
+            for _ in 0..rng.range(1..=3) {
+                for &light in &buttons[rng.index(buttons.len())] {
+                    diagram[light] = !diagram[light];
+                }
+            }
+
+            let mut joltages = vec![0; lights];
+            for button in &buttons {
+                let presses = rng.range(0..=10);
+                for &light in button {
+                    joltages[light] += presses;
+                }
+            }
+
+            format!(
+                "[{}] {} {{{}}}\n",
+                diagram
+                    .iter()
+                    .map(|&on| if on { '#' } else { '.' })
+                    .collect::<String>(),
+                buttons
+                    .iter()
+                    .map(|button| format!("({})", button.iter().join(",")))
+                    .join(" "),
+                joltages.iter().join(",")
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng_range() {
+        let mut rng = Rng::new(42);
+        for _ in 0..1000 {
+            assert!((3..=7).contains(&rng.range(3..=7)));
+        }
+        assert_eq!(rng.range(5..=5), 5);
+    }
+
+    #[test]
+    fn test_same_seed_same_input() {
+        for day in 1..=10 {
+            assert_eq!(generate(day, 20, 7), generate(day, 20, 7));
+            assert_ne!(generate(day, 20, 7), generate(day, 20, 8));
+        }
+        assert_eq!(generate(11, 20, 7), Err(GenerateError::NoGenerator(11)));
+    }
+
+    #[test]
+    fn test_max_size() {
+        assert!(generate(9, 24_999, 0).is_ok());
+        assert_eq!(
+            generate(9, 30_000, 0),
+            Err(GenerateError::TooLarge {
+                day: 9,
+                size: 30_000,
+                max: 24_999
+            })
+        );
+        assert!(matches!(
+            generate(8, 65_537, 0),
+            Err(GenerateError::TooLarge { day: 8, .. })
+        ));
+    }
+
+    #[test]
+    fn test_generated_inputs_parse() {
+        for day in crate::days() {
+            for seed in 0..20 {
+                let input = generate(day.number(), 30, seed).unwrap();
+                if let Err(error) = day.parse_boxed(&input) {
+                    panic!("Seed {}:\n{}", seed, error.render());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_day06_columns_have_no_gaps() {
+        for seed in 0..50 {
+            let input = day06(&mut Rng::new(seed), 20);
+            let lines: Vec<&str> = input.lines().collect();
+            for column in 0..lines[0].len() {
+                let digits: String = lines[..lines.len() - 1]
+                    .iter()
+                    .map(|line| line.as_bytes()[column] as char)
+                    .collect();
+                assert!(!digits.trim().contains(' '), "Gap in column {}", column);
+            }
+        }
+    }
+}
@@ -0,0 +1,336 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::parse::ParseError;
+
+/// Position of a cell in a grid: `(row, column)`.
+pub type Position = (usize, usize);
+
+#[rustfmt::skip]
+const DIRECTIONS_4: [(isize, isize); 4] = [          (-1,  0),
+                                           ( 0, -1),           ( 0,  1),
+                                                     ( 1,  0)          ];
+
+#[rustfmt::skip]
+const DIRECTIONS_8: [(isize, isize); 8] = [(-1, -1), (-1,  0), (-1,  1),
+                                           ( 0, -1),           ( 0,  1),
+                                           ( 1, -1), ( 1,  0), ( 1,  1)];
+
+/// A rectangular 2D grid of cells stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Creates a grid from its rows. Returns `None` if the rows don't all have
+    /// the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a grid with one character per cell, using `parse` to convert
+    /// each character. Parse errors are reported for the given day.
+    pub fn parse_with<F>(day: u8, input: &str, mut parse: F) -> Result<Grid<T>, ParseError>
+    where
+        F: FnMut(char) -> Result<T, String>,
+    {
+        let mut rows = Vec::new();
+        for line in input.lines() {
+            let row = line
+                .char_indices()
+                .map(|(index, c)| {
+                    parse(c).map_err(|message| {
+                        ParseError::at(day, input, &line[index..index + c.len_utf8()], message)
+                    })
+                })
+                .collect::<Result<Vec<T>, _>>()?;
+            if let Some(first) = rows.first().map(Vec::len)
+                && first != row.len()
+            {
+                return Err(ParseError::at(
+                    day,
+                    input,
+                    line,
+                    format!("Expected {} cells, found {}", first, row.len()),
+                ));
+            }
+            rows.push(row);
+        }
+        Ok(Grid::from_rows(rows).expect("The rows have the same length"))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, column): Position) -> bool {
+        row < self.height && column < self.width
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.0 * self.width + position.1])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.contains(position)
+            .then(|| &mut self.cells[position.0 * self.width + position.1])
+    }
+
+    /// Returns the position at the given offset from `position`, if it's
+    /// inside the grid.
+    pub fn offset(
+        &self,
+        (row, column): Position,
+        (delta_row, delta_column): (isize, isize),
+    ) -> Option<Position> {
+        let position = (
+            row.checked_add_signed(delta_row)?,
+            column.checked_add_signed(delta_column)?,
+        );
+        self.contains(position).then_some(position)
+    }
+
+    /// Positions of the orthogonal neighbours of a cell that are inside the grid.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        DIRECTIONS_4
+            .iter()
+            .filter_map(move |&delta| self.offset(position, delta))
+    }
+
+    /// Positions of the orthogonal and diagonal neighbours of a cell that are
+    /// inside the grid.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        DIRECTIONS_8
+            .iter()
+            .filter_map(move |&delta| self.offset(position, delta))
+    }
+
+    /// Positions of every cell, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(column < self.width, "Column out of bounds");
+        self.cells.iter().skip(column).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Returns the grid with its rows and columns swapped.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |(row, column)| (column, row))
+    }
+
+    /// Returns the grid rotated 90 degrees clockwise.
+    pub fn rotate_right(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.rebuild(self.height, self.width, |(row, column)| {
+            (height - 1 - column, row)
+        })
+    }
+
+    /// Returns the grid rotated 90 degrees anti-clockwise.
+    pub fn rotate_left(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.rebuild(self.height, self.width, |(row, column)| {
+            (column, width - 1 - row)
+        })
+    }
+
+    /// Creates a grid of the given size where each cell is copied from the
+    /// position of this grid returned by `source`.
+    fn rebuild<F>(&self, width: usize, height: usize, source: F) -> Grid<T>
+    where
+        T: Clone,
+        F: Fn(Position) -> Position,
+    {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |column| (row, column)))
+            .map(|position| self[source(position)].clone())
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    /// Parses a grid with one character per cell, converting each character
+    /// with `TryFrom<char>`.
+    pub fn parse(day: u8, input: &str) -> Result<Grid<T>, ParseError> {
+        Grid::parse_with(day, input, |c| {
+            T::try_from(c).map_err(|error| error.to_string())
+        })
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position).expect("Position out of bounds")
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position).expect("Position out of bounds")
+    }
+}
+
+impl<T> Display for Grid<T>
+where
+    T: Copy,
+    char: From<T>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            for &cell in row {
+                write!(f, "{}", char::from(cell))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse(0, "abc\ndef\n").unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 2)], 'f');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Grid::<char>::parse(4, "abc\nde\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.message, "Expected 3 cells, found 2");
+
+        let error = Grid::parse_with(4, "..\n.x\n", |c| match c {
+            '.' => Ok(()),
+            _ => Err(format!("Unknown character '{}'", c)),
+        })
+        .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbours8((1, 1)).collect::<Vec<_>>(),
+            vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&['a', 'b', 'c'], &['d', 'e', 'f']]
+        );
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["ad", "be", "cf"]
+        );
+        assert_eq!(grid.positions().count(), 6);
+    }
+
+    #[test]
+    fn test_transformations() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_left().rotate_right(), grid);
+    }
+}
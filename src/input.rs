@@ -0,0 +1,137 @@
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{self, BufRead, BufReader, Read},
+    path::PathBuf,
+};
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Path(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Returns the source designated by a command line argument. `-` designates
+    /// stdin and no argument designates the default `inputs/NN.txt` file.
+    pub fn from_arg(day: u8, arg: Option<&str>) -> Source {
+        match arg {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::Path(PathBuf::from(path)),
+            None => Source::Path(default_path(day)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            Source::Path(path) => std::fs::read_to_string(path),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        };
+        result.map_err(|error| InputError {
+            source: self.clone(),
+            error,
+        })
+    }
+
+    /// Opens the source to read it progressively instead of all at once.
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            Source::Path(path) => match File::open(path) {
+                Ok(file) => Ok(Box::new(BufReader::new(file))),
+                Err(error) => Err(InputError {
+                    source: self.clone(),
+                    error,
+                }),
+            },
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Path(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub source: Source,
+    pub error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Unable to read the input from {}: {}",
+            self.source, self.error
+        )
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Path of the input of a day when none is given: `inputs/NN.txt`.
+pub fn default_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("inputs/{:02}.txt", day))
+}
+
+/// Path of the example given in the puzzle statement: `examples/NN.txt`.
+pub fn example_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("examples/{:02}.txt", day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_from_arg() {
+        assert_eq!(
+            Source::from_arg(7, None),
+            Source::Path(PathBuf::from("inputs/07.txt"))
+        );
+        assert_eq!(Source::from_arg(7, Some("-")), Source::Stdin);
+        assert_eq!(
+            Source::from_arg(7, Some("examples/07.txt")),
+            Source::Path(PathBuf::from("examples/07.txt"))
+        );
+    }
+
+    #[test]
+    fn test_open() {
+        let source = Source::Path(example_path(1));
+        let lines = source.open().unwrap().lines().count();
+        assert_eq!(lines, source.read().unwrap().lines().count());
+
+        let source = Source::Path(PathBuf::from("inputs/does-not-exist.txt"));
+        assert_eq!(
+            source.open().err().unwrap().error.kind(),
+            io::ErrorKind::NotFound
+        );
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        let source = Source::Path(PathBuf::from("inputs/does-not-exist.txt"));
+        let error = source.read().unwrap_err();
+        assert_eq!(error.error.kind(), io::ErrorKind::NotFound);
+        assert!(
+            error
+                .to_string()
+                .starts_with("Unable to read the input from inputs/does-not-exist.txt")
+        );
+    }
+}
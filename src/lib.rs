@@ -0,0 +1,117 @@
+use std::{any::Any, fmt::Display};
+
+use parse::ParseError;
+
+pub mod answers;
+pub mod cli;
+pub mod days;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod output;
+pub mod parse;
+pub mod runner;
+
+/// A puzzle solution for a single day.
+///
+/// The input is parsed once with `parse` and the resulting data is shared by
+/// both parts.
+pub trait Solution {
+    /// Day of the puzzle, from 1 to 12.
+    const DAY: u8;
+
+    type Data;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Data, ParseError>;
+    fn part1(data: &Self::Data) -> Self::Answer1;
+    fn part2(data: &Self::Data) -> Self::Answer2;
+}
+
+/// Answer of a part that has not been solved yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unsolved")
+    }
+}
+
+/// Type erased version of `Solution`, so the days can be stored together and
+/// selected at runtime.
+pub trait Day {
+    fn number(&self) -> u8;
+    fn parse_boxed(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+    fn part1_boxed(&self, data: &dyn Any) -> String;
+    fn part2_boxed(&self, data: &dyn Any) -> String;
+}
+
+impl<S> Day for S
+where
+    S: Solution,
+    S::Data: 'static,
+{
+    fn number(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse_boxed(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn part1_boxed(&self, data: &dyn Any) -> String {
+        let data = data
+            .downcast_ref::<S::Data>()
+            .expect("Data was not parsed by the same day");
+        S::part1(data).to_string()
+    }
+
+    fn part2_boxed(&self, data: &dyn Any) -> String {
+        let data = data
+            .downcast_ref::<S::Data>()
+            .expect("Data was not parsed by the same day");
+        S::part2(data).to_string()
+    }
+}
+
+/// Returns every implemented day, in order.
+pub fn days() -> Vec<&'static dyn Day> {
+    vec![
+        &days::day01::Day01,
+        &days::day02::Day02,
+        &days::day03::Day03,
+        &days::day04::Day04,
+        &days::day05::Day05,
+        &days::day06::Day06,
+        &days::day07::Day07,
+        &days::day08::Day08,
+        &days::day09::Day09,
+        &days::day10::Day10,
+    ]
+}
+
+/// Returns the day with the given number, if it is implemented.
+pub fn day(number: u8) -> Option<&'static dyn Day> {
+    days().into_iter().find(|day| day.number() == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_numbered_in_order() {
+        let numbers: Vec<u8> = days().iter().map(|day| day.number()).collect();
+        assert_eq!(numbers, (1..=10).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn test_boxed_day() {
+        let day = day(1).unwrap();
+        let data = day.parse_boxed(include_str!("../examples/01.txt")).unwrap();
+        assert_eq!(day.part1_boxed(data.as_ref()), "3");
+        assert_eq!(day.part2_boxed(data.as_ref()), "6");
+    }
+}
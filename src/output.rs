@@ -0,0 +1,170 @@
+//! Output formats of the binaries. Besides the text meant for humans, results
+//! can be printed as JSON lines: one JSON object per line, so they can be
+//! ingested without scraping the text.
+
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::{
+    answers::Verdict,
+    parse::ParseError,
+    runner::{DayReport, PartReport},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Invalid format: {}", format)),
+        }
+    }
+}
+
+/// The answer of a part, with the timings of the run that produced it.
+#[derive(Debug, Serialize)]
+pub struct PartRecord<'a> {
+    pub day: u8,
+    pub part: u8,
+    pub answer: &'a str,
+    /// Time spent solving the part.
+    pub elapsed_ns: u64,
+    /// Time spent parsing the input, shared by both parts.
+    pub parse_ns: u64,
+    /// Hash of the input, as 16 hexadecimal digits.
+    pub input_hash: String,
+    /// Confirmed answer, when verifying.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<&'a str>,
+    /// `correct`, `incorrect` or `unknown`, when verifying.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<&'static str>,
+}
+
+impl<'a> PartRecord<'a> {
+    pub fn new(report: &DayReport, part: &'a PartReport) -> PartRecord<'a> {
+        PartRecord {
+            day: report.day,
+            part: part.part.number(),
+            answer: &part.answer,
+            elapsed_ns: part.elapsed.as_nanos() as u64,
+            parse_ns: report.parse.as_nanos() as u64,
+            input_hash: format!("{:016x}", report.input_hash),
+            expected: None,
+            verdict: None,
+        }
+    }
+
+    /// Adds the result of the comparison with the confirmed answer.
+    pub fn with_verdict(mut self, expected: Option<&'a str>, verdict: &Verdict) -> PartRecord<'a> {
+        self.expected = expected;
+        self.verdict = Some(match verdict {
+            Verdict::Correct => "correct",
+            Verdict::Incorrect { .. } => "incorrect",
+            Verdict::Unknown => "unknown",
+        });
+        self
+    }
+}
+
+/// An error preventing a day from being solved. Parse errors also have the
+/// position of the error in the input.
+#[derive(Debug, Serialize)]
+pub struct ErrorRecord {
+    pub day: u8,
+    pub error: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
+}
+
+impl ErrorRecord {
+    pub fn new(day: u8, error: impl ToString) -> ErrorRecord {
+        ErrorRecord {
+            day,
+            error: error.to_string(),
+            line: None,
+            column: None,
+        }
+    }
+}
+
+impl From<&ParseError> for ErrorRecord {
+    fn from(error: &ParseError) -> Self {
+        ErrorRecord {
+            day: error.day,
+            error: error.message.clone(),
+            line: Some(error.line),
+            column: Some(error.column),
+        }
+    }
+}
+
+/// Prints a record on its own line.
+pub fn print_json<T: Serialize>(record: &T) {
+    println!(
+        "{}",
+        serde_json::to_string(record).expect("Records are always serializable")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{answers, runner::Part};
+
+    #[test]
+    fn test_part_record() {
+        let report = DayReport {
+            day: 3,
+            input_hash: 0xabc,
+            parse: Duration::from_micros(2),
+            parts: vec![PartReport {
+                part: Part::Two,
+                answer: "42".to_string(),
+                elapsed: Duration::from_nanos(1500),
+            }],
+        };
+        let record = PartRecord::new(&report, &report.parts[0]);
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r#"{"day":3,"part":2,"answer":"42","elapsed_ns":1500,"parse_ns":2000,"input_hash":"0000000000000abc"}"#
+        );
+
+        let record = record.with_verdict(Some("41"), &answers::check(Some("41"), "42"));
+        let json = serde_json::to_string(&record).unwrap();
+        assert!(json.ends_with(r#""expected":"41","verdict":"incorrect"}"#));
+    }
+
+    #[test]
+    fn test_error_record() {
+        let input = "L68\nX30\n";
+        let error = ParseError::at(1, input, &input[4..5], "Invalid direction 'X'");
+        assert_eq!(
+            serde_json::to_string(&ErrorRecord::from(&error)).unwrap(),
+            r#"{"day":1,"error":"Invalid direction 'X'","line":2,"column":1}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&ErrorRecord::new(4, "Missing input")).unwrap(),
+            r#"{"day":4,"error":"Missing input"}"#
+        );
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+}
@@ -0,0 +1,143 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Error found while parsing the input of a day, with its location.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// Line of the error, starting at 1.
+    pub line: usize,
+    /// Column of the error in characters, starting at 1.
+    pub column: usize,
+    /// The offending text, empty if something is missing.
+    pub text: String,
+    /// The whole line containing the error.
+    pub source_line: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error for the text starting at byte `offset` of the input and
+    /// spanning `len` bytes.
+    pub fn at_offset(
+        day: u8,
+        input: &str,
+        offset: usize,
+        len: usize,
+        message: impl Into<String>,
+    ) -> ParseError {
+        let offset = offset.min(input.len());
+        let line_start = input[..offset].rfind('\n').map_or(0, |index| index + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |index| offset + index);
+        let source_line = input[line_start..line_end].trim_end_matches('\r');
+        let end = (offset + len).min(line_end);
+
+        ParseError {
+            day,
+            line: input[..line_start].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            text: input[offset..end].to_string(),
+            source_line: source_line.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error for `span`, which must be a slice of `input`.
+    pub fn at(day: u8, input: &str, span: &str, message: impl Into<String>) -> ParseError {
+        ParseError::at_offset(day, input, offset_of(input, span), span.len(), message)
+    }
+
+    /// Renders the error with the offending line and a caret under the
+    /// offending text.
+    pub fn render(&self) -> String {
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+        let carets = "^".repeat(self.text.chars().count().max(1));
+        format!(
+            "error: {}\n{} --> day {}, line {}, column {}\n{} |\n{} | {}\n{} | {}{}",
+            self.message,
+            margin,
+            self.day,
+            self.line,
+            self.column,
+            margin,
+            number,
+            self.source_line,
+            margin,
+            " ".repeat(self.column - 1),
+            carets
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Day {}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Byte offset of `span` in `input`. `span` must be a slice of `input`.
+pub fn offset_of(input: &str, span: &str) -> usize {
+    let offset = (span.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+    debug_assert!(offset <= input.len(), "The span is not part of the input");
+    offset.min(input.len())
+}
+
+/// Parses `span`, a slice of `input`, reporting its location on failure.
+pub fn parse_number<T: FromStr>(day: u8, input: &str, span: &str) -> Result<T, ParseError> {
+    span.parse()
+        .map_err(|_| ParseError::at(day, input, span, format!("Invalid number '{}'", span)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at() {
+        let input = "L68\nL30\nX48\n";
+        let error = ParseError::at(1, input, &input[8..9], "Invalid direction");
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 1);
+        assert_eq!(error.text, "X");
+        assert_eq!(error.source_line, "X48");
+    }
+
+    #[test]
+    fn test_at_end_of_input() {
+        let input = "1-2,3";
+        let error = ParseError::at_offset(2, input, input.len(), 0, "Missing end of range");
+        assert_eq!((error.line, error.column), (1, 6));
+        assert_eq!(error.text, "");
+    }
+
+    #[test]
+    fn test_render() {
+        let input = "...\n.x.\n";
+        let error = ParseError::at(4, input, &input[5..6], "Unknown character 'x'");
+        assert_eq!(
+            error.render(),
+            "error: Unknown character 'x'\n  --> day 4, line 2, column 2\n  |\n2 | .x.\n  |  ^"
+        );
+        assert_eq!(
+            error.to_string(),
+            "Day 4, line 2, column 2: Unknown character 'x'"
+        );
+    }
+
+    #[test]
+    fn test_parse_number() {
+        let input = "12,a4";
+        assert_eq!(parse_number::<u64>(9, input, &input[..2]), Ok(12));
+        let error = parse_number::<u64>(9, input, &input[3..]).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 4));
+        assert_eq!(error.text, "a4");
+    }
+}
@@ -0,0 +1,164 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use crate::{Day, parse::ParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.number().fmt(f)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub part: Part,
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct DayReport {
+    pub day: u8,
+    /// Hash of the input, see `hash_input`.
+    pub input_hash: u64,
+    pub parse: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+impl DayReport {
+    pub fn part(&self, part: Part) -> Option<&PartReport> {
+        self.parts.iter().find(|report| report.part == part)
+    }
+
+    /// Total time spent parsing and solving the parts.
+    pub fn total(&self) -> Duration {
+        self.parse
+            + self
+                .parts
+                .iter()
+                .map(|report| report.elapsed)
+                .sum::<Duration>()
+    }
+}
+
+/// FNV-1a hash of an input. It's not cryptographic, but it's stable across
+/// builds and platforms, so it can tell which input an answer was computed on.
+pub fn hash_input(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Parses the input of a day and solves the requested parts, timing each phase.
+pub fn run(day: &dyn Day, input: &str, parts: &[Part]) -> Result<DayReport, ParseError> {
+    let start = Instant::now();
+    let data = day.parse_boxed(input)?;
+    let parse = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => day.part1_boxed(data.as_ref()),
+                Part::Two => day.part2_boxed(data.as_ref()),
+            };
+            PartReport {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(DayReport {
+        day: day.number(),
+        input_hash: hash_input(input),
+        parse,
+        parts,
+    })
+}
+
+/// Parses a selection of days like `all`, `7`, `1-5` or `1,3,5-7`.
+pub fn parse_days(selection: &str) -> Result<Vec<u8>, String> {
+    if selection == "all" {
+        return Ok(crate::days().iter().map(|day| day.number()).collect());
+    }
+
+    let parse_day = |day: &str| {
+        day.trim()
+            .parse::<u8>()
+            .map_err(|_| format!("Invalid day: {}", day))
+    };
+
+    let mut days = Vec::new();
+    for item in selection.split(',') {
+        match item.split_once('-') {
+            Some((first, last)) => {
+                let (first, last) = (parse_day(first)?, parse_day(last)?);
+                if last < first {
+                    return Err(format!("Invalid range of days: {}", item));
+                }
+                days.extend(first..=last);
+            }
+            None => days.push(parse_day(item)?),
+        }
+    }
+    days.sort();
+    days.dedup();
+    Ok(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("7"), Ok(vec![7]));
+        assert_eq!(parse_days("1-3"), Ok(vec![1, 2, 3]));
+        assert_eq!(parse_days("5-7,1,6"), Ok(vec![1, 5, 6, 7]));
+        assert_eq!(parse_days("all"), Ok((1..=10).collect()));
+        assert!(parse_days("3-1").is_err());
+        assert!(parse_days("x").is_err());
+    }
+
+    #[test]
+    fn test_run() {
+        let day = crate::day(3).unwrap();
+        let report = run(day, include_str!("../examples/03.txt"), &[Part::Two]).unwrap();
+        assert_eq!(report.day, 3);
+        assert!(report.part(Part::One).is_none());
+        assert_eq!(report.part(Part::Two).unwrap().answer, "3121910778619");
+        assert_eq!(
+            report.input_hash,
+            hash_input(include_str!("../examples/03.txt"))
+        );
+    }
+
+    #[test]
+    fn test_hash_input() {
+        assert_eq!(hash_input(""), 0xcbf29ce484222325);
+        assert_eq!(hash_input("a"), 0xaf63dc4c8601ec8c);
+        assert_ne!(hash_input("L68\n"), hash_input("L86\n"));
+    }
+}